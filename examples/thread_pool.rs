@@ -0,0 +1,80 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use rust_ds::concurrent::{Steal, Stealer, Worker};
+
+enum Task {
+    Fib(u64),
+}
+
+fn fib(n: u64) -> u64 {
+    if n < 2 {
+        n
+    } else {
+        fib(n - 1) + fib(n - 2)
+    }
+}
+
+fn find_task(local: &Worker<Task>, stealers: &[Stealer<Task>], me: usize) -> Option<Task> {
+    if let Some(task) = local.pop() {
+        return Some(task);
+    }
+    loop {
+        let mut retry = false;
+        for (i, stealer) in stealers.iter().enumerate() {
+            if i == me {
+                continue;
+            }
+            match stealer.steal_batch(local) {
+                Steal::Success(_) => return local.pop(),
+                Steal::Retry => retry = true,
+                Steal::Empty => {}
+            }
+        }
+        if !retry {
+            return None;
+        }
+    }
+}
+
+fn main() {
+    let threads = thread::available_parallelism().map_or(4, |n| n.get());
+    let workers: Vec<_> = (0..threads).map(|_| Worker::new()).collect();
+    let stealers: Vec<_> = workers.iter().map(Worker::stealer).collect();
+    // all the work starts on one thread and spreads out through stealing
+    for n in 0..1000 {
+        workers[0].push(Task::Fib(n % 25));
+    }
+    let pending = AtomicUsize::new(1000);
+    let total = AtomicUsize::new(0);
+    let executed: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = workers
+            .into_iter()
+            .enumerate()
+            .map(|(me, local)| {
+                let (stealers, pending, total) = (&stealers, &pending, &total);
+                scope.spawn(move || {
+                    let mut executed = 0;
+                    while pending.load(Ordering::Acquire) > 0 {
+                        match find_task(&local, stealers, me) {
+                            Some(Task::Fib(n)) => {
+                                total.fetch_add(fib(n) as usize, Ordering::Relaxed);
+                                pending.fetch_sub(1, Ordering::Release);
+                                executed += 1;
+                            }
+                            None => thread::yield_now(),
+                        }
+                    }
+                    executed
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (i, count) in executed.iter().enumerate() {
+        println!("thread {i} executed {count} tasks");
+    }
+    println!("sum of results: {}", total.load(Ordering::Relaxed));
+}
@@ -0,0 +1,277 @@
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    sync::atomic::{fence, AtomicIsize, AtomicPtr, Ordering},
+};
+
+use super::hazard::Domain;
+
+const MIN_CAPACITY: usize = 16;
+
+struct Buffer<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+}
+
+impl<T> Buffer<T> {
+    fn new(capacity: usize) -> *mut Buffer<T> {
+        Box::into_raw(Box::new(Buffer {
+            slots: (0..capacity)
+                .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+                .collect(),
+        }))
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn slot(&self, index: isize) -> *mut MaybeUninit<T> {
+        self.slots[index as usize & (self.capacity() - 1)].get()
+    }
+
+    unsafe fn write(&self, index: isize, value: T) {
+        self.slot(index).write(MaybeUninit::new(value));
+    }
+
+    // the copy may be torn by a concurrent write and is only valid once the read is confirmed
+    unsafe fn read(&self, index: isize) -> MaybeUninit<T> {
+        self.slot(index).read_volatile()
+    }
+}
+
+struct Inner<T> {
+    top: AtomicIsize,
+    bottom: AtomicIsize,
+    buffer: AtomicPtr<Buffer<T>>,
+    domain: Domain,
+}
+
+pub struct Worker<T> {
+    inner: Arc<Inner<T>>,
+    phantom: PhantomData<*mut ()>,
+}
+
+pub struct Stealer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Steal<T> {
+    Empty,
+    Success(T),
+    Retry,
+}
+
+impl<T> Steal<T> {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Steal::Empty)
+    }
+
+    pub fn is_retry(&self) -> bool {
+        matches!(self, Steal::Retry)
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Steal<U> {
+        match self {
+            Steal::Empty => Steal::Empty,
+            Steal::Success(value) => Steal::Success(f(value)),
+            Steal::Retry => Steal::Retry,
+        }
+    }
+
+    pub fn success(self) -> Option<T> {
+        match self {
+            Steal::Success(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Inner<T> {
+    fn _len(&self) -> usize {
+        let bottom = self.bottom.load(Ordering::Acquire);
+        let top = self.top.load(Ordering::Acquire);
+        (bottom - top).max(0) as usize
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        let buffer = unsafe { Box::from_raw(*self.buffer.get_mut()) };
+        for index in *self.top.get_mut()..*self.bottom.get_mut() {
+            unsafe { buffer.read(index).assume_init_drop() };
+        }
+    }
+}
+
+// Worker
+
+impl<T> Worker<T> {
+    pub fn new() -> Worker<T> {
+        Worker {
+            inner: Arc::new(Inner {
+                top: AtomicIsize::new(0),
+                bottom: AtomicIsize::new(0),
+                buffer: AtomicPtr::new(Buffer::new(MIN_CAPACITY)),
+                domain: Domain::new(),
+            }),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn stealer(&self) -> Stealer<T> {
+        Stealer {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner._len()
+    }
+
+    fn _grow(&self, top: isize, bottom: isize, old: &Buffer<T>) -> *mut Buffer<T> {
+        let new = Buffer::new(old.capacity() * 2);
+        for index in top..bottom {
+            unsafe { (*new).write(index, old.read(index).assume_init()) };
+        }
+        let old = self.inner.buffer.swap(new, Ordering::Release);
+        unsafe { self.inner.domain.guard().retire(old) };
+        new
+    }
+
+    pub fn push(&self, value: T) {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::Relaxed);
+        let top = inner.top.load(Ordering::Acquire);
+        let mut buffer = inner.buffer.load(Ordering::Relaxed);
+        if bottom - top >= unsafe { (*buffer).capacity() } as isize {
+            buffer = self._grow(top, bottom, unsafe { &*buffer });
+        }
+        unsafe { (*buffer).write(bottom, value) };
+        fence(Ordering::Release);
+        inner.bottom.store(bottom + 1, Ordering::Relaxed);
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::Relaxed) - 1;
+        let buffer = inner.buffer.load(Ordering::Relaxed);
+        inner.bottom.store(bottom, Ordering::Relaxed);
+        fence(Ordering::SeqCst);
+        let top = inner.top.load(Ordering::Relaxed);
+        if top > bottom {
+            inner.bottom.store(bottom + 1, Ordering::Relaxed);
+            return None;
+        }
+        let value = unsafe { (*buffer).read(bottom) };
+        if top == bottom {
+            let won = inner
+                .top
+                .compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok();
+            inner.bottom.store(bottom + 1, Ordering::Relaxed);
+            if !won {
+                return None;
+            }
+        }
+        Some(unsafe { value.assume_init() })
+    }
+}
+
+impl<T> Debug for Worker<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Worker").field("len", &self.len()).finish()
+    }
+}
+
+impl<T> Default for Worker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T: Send> Send for Worker<T> {}
+
+// Stealer
+
+impl<T> Stealer<T> {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner._len()
+    }
+
+    pub fn steal(&self) -> Steal<T> {
+        let inner = &*self.inner;
+        let top = inner.top.load(Ordering::Acquire);
+        fence(Ordering::SeqCst);
+        let bottom = inner.bottom.load(Ordering::Acquire);
+        if top >= bottom {
+            return Steal::Empty;
+        }
+        let guard = inner.domain.guard();
+        let buffer = guard.protect(0, &inner.buffer);
+        let value = unsafe { (*buffer).read(top) };
+        if inner
+            .top
+            .compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::Relaxed)
+            .is_err()
+        {
+            return Steal::Retry;
+        }
+        Steal::Success(unsafe { value.assume_init() })
+    }
+
+    // moves up to half of the tasks into `dest`, returning how many were moved;
+    // each task is claimed with its own CAS because `pop` only synchronizes
+    // with thieves on the last task, so a multi-slot claim could overlap it
+    pub fn steal_batch(&self, dest: &Worker<T>) -> Steal<usize> {
+        if Arc::ptr_eq(&self.inner, &dest.inner) {
+            return Steal::Empty;
+        }
+        let count = self.len().div_ceil(2);
+        let mut moved = 0;
+        while moved < count {
+            match self.steal() {
+                Steal::Success(value) => {
+                    dest.push(value);
+                    moved += 1;
+                }
+                Steal::Retry if moved == 0 => return Steal::Retry,
+                Steal::Empty | Steal::Retry => break,
+            }
+        }
+        if moved == 0 {
+            Steal::Empty
+        } else {
+            Steal::Success(moved)
+        }
+    }
+}
+
+impl<T> Clone for Stealer<T> {
+    fn clone(&self) -> Self {
+        Stealer {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Debug for Stealer<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Stealer").field("len", &self.len()).finish()
+    }
+}
+
+unsafe impl<T: Send> Send for Stealer<T> {}
+
+unsafe impl<T: Send> Sync for Stealer<T> {}
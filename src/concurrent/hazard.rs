@@ -0,0 +1,171 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cell::UnsafeCell,
+    ptr,
+    sync::atomic::{fence, AtomicBool, AtomicPtr, Ordering},
+};
+
+const SLOTS: usize = 2;
+const RETIRED_THRESHOLD: usize = 64;
+
+struct Retired {
+    ptr: *mut u8,
+    reclaim: unsafe fn(*mut u8),
+}
+
+struct Record {
+    active: AtomicBool,
+    hazards: [AtomicPtr<u8>; SLOTS],
+    retired: UnsafeCell<Vec<Retired>>,
+    next: *mut Record,
+}
+
+// records are only ever added, each one is owned by at most one guard at a time
+pub(crate) struct Domain {
+    records: AtomicPtr<Record>,
+}
+
+pub(crate) struct Guard<'a> {
+    domain: &'a Domain,
+    record: &'a Record,
+}
+
+impl Domain {
+    pub(crate) fn new() -> Domain {
+        Domain {
+            records: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    fn _records(&self) -> impl Iterator<Item = &Record> {
+        let mut record = self.records.load(Ordering::Acquire);
+        core::iter::from_fn(move || {
+            let current = unsafe { record.as_ref()? };
+            record = current.next;
+            Some(current)
+        })
+    }
+
+    pub(crate) fn guard(&self) -> Guard<'_> {
+        for record in self._records() {
+            if !record.active.load(Ordering::Relaxed)
+                && record
+                    .active
+                    .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                return Guard {
+                    domain: self,
+                    record,
+                };
+            }
+        }
+        let record = Box::into_raw(Box::new(Record {
+            active: AtomicBool::new(true),
+            hazards: Default::default(),
+            retired: UnsafeCell::new(Vec::new()),
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.records.load(Ordering::Acquire);
+        loop {
+            unsafe { (*record).next = head };
+            match self.records.compare_exchange_weak(
+                head,
+                record,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+        Guard {
+            domain: self,
+            record: unsafe { &*record },
+        }
+    }
+}
+
+impl Drop for Domain {
+    fn drop(&mut self) {
+        let mut record = *self.records.get_mut();
+        while !record.is_null() {
+            let boxed = unsafe { Box::from_raw(record) };
+            for retired in boxed.retired.into_inner() {
+                unsafe { (retired.reclaim)(retired.ptr) };
+            }
+            record = boxed.next;
+        }
+    }
+}
+
+unsafe impl Send for Domain {}
+
+unsafe impl Sync for Domain {}
+
+impl Guard<'_> {
+    // loads `src` and publishes it in `slot` until it is known to be protected
+    pub(crate) fn protect<T>(&self, slot: usize, src: &AtomicPtr<T>) -> *mut T {
+        let mut ptr = src.load(Ordering::SeqCst);
+        loop {
+            self.record.hazards[slot].store(ptr.cast(), Ordering::SeqCst);
+            let reloaded = src.load(Ordering::SeqCst);
+            if reloaded == ptr {
+                return ptr;
+            }
+            ptr = reloaded;
+        }
+    }
+
+    pub(crate) fn set<T>(&self, slot: usize, ptr: *mut T) {
+        self.record.hazards[slot].store(ptr.cast(), Ordering::SeqCst);
+    }
+
+    pub(crate) fn clear(&self) {
+        for hazard in &self.record.hazards {
+            hazard.store(ptr::null_mut(), Ordering::Release);
+        }
+    }
+
+    // `ptr` must be a leaked Box<T> that is no longer reachable from the shared structure
+    pub(crate) unsafe fn retire<T>(&self, ptr: *mut T) {
+        unsafe fn reclaim<T>(ptr: *mut u8) {
+            drop(Box::from_raw(ptr.cast::<T>()));
+        }
+        let retired = &mut *self.record.retired.get();
+        retired.push(Retired {
+            ptr: ptr.cast(),
+            reclaim: reclaim::<T>,
+        });
+        if retired.len() >= RETIRED_THRESHOLD {
+            self._scan(retired);
+        }
+    }
+
+    fn _scan(&self, retired: &mut Vec<Retired>) {
+        // orders the unlinking of every retired pointer before the hazard
+        // reads, pairing with the SeqCst publish-and-reload in `protect`
+        fence(Ordering::SeqCst);
+        let hazards: Vec<*mut u8> = self
+            .domain
+            ._records()
+            .flat_map(|record| &record.hazards)
+            .map(|hazard| hazard.load(Ordering::SeqCst))
+            .filter(|ptr| !ptr.is_null())
+            .collect();
+        retired.retain(|retired| {
+            if hazards.contains(&retired.ptr) {
+                return true;
+            }
+            unsafe { (retired.reclaim)(retired.ptr) };
+            false
+        });
+    }
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        self.clear();
+        self.record.active.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,9 @@
+mod deque;
+mod hazard;
+mod queue;
+
+#[cfg(test)]
+mod tests;
+
+pub use deque::{Steal, Stealer, Worker};
+pub use queue::Queue;
@@ -0,0 +1,261 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use super::*;
+use crate::test_util::*;
+
+#[test]
+fn test_queue_fifo() {
+    let queue = Queue::new();
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop(), None);
+    for i in 0..100 {
+        queue.push(i);
+    }
+    assert!(!queue.is_empty());
+    for i in 0..100 {
+        assert_eq!(queue.pop(), Some(i));
+    }
+    assert_eq!(queue.pop(), None);
+    let queue: Queue<_> = (0..5).collect();
+    assert_eq!(
+        std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn test_queue_drop() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Elem;
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let queue = Queue::new();
+    for _ in 0..200 {
+        queue.push(Elem);
+    }
+    for _ in 0..150 {
+        drop(queue.pop());
+    }
+    assert_eq!(DROPS.load(Ordering::Relaxed), 150);
+    drop(queue);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 200);
+}
+
+#[test]
+fn test_queue_stress() {
+    const PRODUCERS: usize = 4;
+    const CONSUMERS: usize = 4;
+    const PER_PRODUCER: usize = 20_000;
+    let queue = Arc::new(Queue::new());
+    let consumed = Arc::new(AtomicUsize::new(0));
+    let producers: Vec<_> = (0..PRODUCERS)
+        .map(|p| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in 0..PER_PRODUCER {
+                    queue.push(p * PER_PRODUCER + i);
+                }
+            })
+        })
+        .collect();
+    let consumers: Vec<_> = (0..CONSUMERS)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let consumed = Arc::clone(&consumed);
+            thread::spawn(move || {
+                let mut received = Vec::new();
+                let mut last_seen = [None; PRODUCERS];
+                while consumed.load(Ordering::Relaxed) < PRODUCERS * PER_PRODUCER {
+                    match queue.pop() {
+                        Some(item) => {
+                            // items of one producer must arrive in order
+                            let producer = item / PER_PRODUCER;
+                            assert!(last_seen[producer] < Some(item));
+                            last_seen[producer] = Some(item);
+                            received.push(item);
+                            consumed.fetch_add(1, Ordering::Relaxed);
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                received
+            })
+        })
+        .collect();
+    producers
+        .into_iter()
+        .for_each(|producer| producer.join().unwrap());
+    let mut all: Vec<_> = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect();
+    all.sort_unstable();
+    assert_eq!(all, (0..PRODUCERS * PER_PRODUCER).collect::<Vec<_>>());
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_deque_owner() {
+    let worker = Worker::new();
+    let stealer = worker.stealer();
+    assert!(worker.is_empty());
+    assert_eq!(worker.pop(), None);
+    assert_eq!(stealer.steal(), Steal::Empty);
+    for i in 0..100 {
+        worker.push(i);
+    }
+    assert_eq!(worker.len(), 100);
+    assert_eq!(stealer.len(), 100);
+    assert_eq!(worker.pop(), Some(99));
+    assert_eq!(stealer.steal(), Steal::Success(0));
+    assert_eq!(stealer.steal().success(), Some(1));
+    for i in (2..99).rev() {
+        assert_eq!(worker.pop(), Some(i));
+    }
+    assert_eq!(worker.pop(), None);
+    assert!(stealer.steal().is_empty());
+}
+
+#[test]
+fn test_deque_steal_batch() {
+    let worker = Worker::new();
+    let thief = Worker::new();
+    let stealer = worker.stealer();
+    assert_eq!(stealer.steal_batch(&thief), Steal::Empty);
+    for i in 0..9 {
+        worker.push(i);
+    }
+    assert_eq!(stealer.steal_batch(&worker), Steal::Empty);
+    assert_eq!(stealer.steal_batch(&thief), Steal::Success(5));
+    assert_eq!(worker.len(), 4);
+    assert_eq!(
+        std::iter::from_fn(|| thief.stealer().steal().success()).collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4]
+    );
+    assert_eq!(stealer.steal_batch(&thief), Steal::Success(2));
+    assert_eq!(stealer.steal_batch(&thief), Steal::Success(1));
+    assert_eq!(stealer.steal_batch(&thief), Steal::Success(1));
+    assert_eq!(stealer.steal_batch(&thief), Steal::Empty);
+    assert_eq!(
+        std::iter::from_fn(|| thief.pop()).collect::<Vec<_>>(),
+        [8, 7, 6, 5]
+    );
+}
+
+#[test]
+fn test_deque_drop() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Elem;
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let worker = Worker::new();
+    let stealer = worker.stealer();
+    for _ in 0..200 {
+        worker.push(Elem);
+    }
+    for _ in 0..50 {
+        drop(worker.pop());
+        drop(stealer.steal());
+    }
+    assert_eq!(DROPS.load(Ordering::Relaxed), 100);
+    drop(worker);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 100);
+    drop(stealer);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 200);
+}
+
+#[test]
+fn test_deque_stress() {
+    const THIEVES: usize = 4;
+    const ITEMS: usize = 100_000;
+    let worker = Worker::new();
+    let done = Arc::new(AtomicUsize::new(0));
+    let thieves: Vec<_> = (0..THIEVES)
+        .map(|t| {
+            let stealer = worker.stealer();
+            let done = Arc::clone(&done);
+            thread::spawn(move || {
+                let local = Worker::new();
+                let mut received = Vec::new();
+                while done.load(Ordering::Acquire) == 0 || !stealer.is_empty() {
+                    let stolen = if t % 2 == 0 {
+                        stealer.steal()
+                    } else {
+                        stealer.steal_batch(&local).map(|_| local.pop().unwrap())
+                    };
+                    match stolen {
+                        Steal::Success(item) => received.push(item),
+                        Steal::Retry => {}
+                        Steal::Empty => thread::yield_now(),
+                    }
+                    received.extend(std::iter::from_fn(|| local.pop()));
+                }
+                received
+            })
+        })
+        .collect();
+    let mut all = Vec::new();
+    for i in 0..ITEMS {
+        worker.push(i);
+        if i % 3 == 0 {
+            all.extend(worker.pop());
+        }
+    }
+    done.store(1, Ordering::Release);
+    all.extend(std::iter::from_fn(|| worker.pop()));
+    all.extend(thieves.into_iter().flat_map(|thief| thief.join().unwrap()));
+    all.sort_unstable();
+    assert_eq!(all, (0..ITEMS).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_deque_steal_batch_against_pop() {
+    const THIEVES: usize = 3;
+    const ROUNDS: usize = 20_000;
+    let worker = Worker::new();
+    let done = Arc::new(AtomicUsize::new(0));
+    let thieves: Vec<_> = (0..THIEVES)
+        .map(|_| {
+            let stealer = worker.stealer();
+            let done = Arc::clone(&done);
+            thread::spawn(move || {
+                let local = Worker::new();
+                let mut received = Vec::new();
+                while done.load(Ordering::Acquire) == 0 {
+                    if stealer.steal_batch(&local).is_empty() {
+                        thread::yield_now();
+                    }
+                    received.extend(std::iter::from_fn(|| local.pop()).map(|item: Box<_>| *item));
+                }
+                received
+            })
+        })
+        .collect();
+    // the owner drains every batch with pop, racing the thieves over the
+    // front of the deque instead of only the last element
+    let mut all = Vec::new();
+    for round in 0..ROUNDS {
+        for i in 0..4 {
+            worker.push(Box::new(round * 4 + i));
+        }
+        all.extend(std::iter::from_fn(|| worker.pop()).map(|item| *item));
+    }
+    done.store(1, Ordering::Release);
+    all.extend(thieves.into_iter().flat_map(|thief| thief.join().unwrap()));
+    all.sort_unstable();
+    assert_eq!(all, (0..ROUNDS * 4).collect::<Vec<_>>());
+}
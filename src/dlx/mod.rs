@@ -0,0 +1,366 @@
+use alloc::{vec, vec::Vec};
+use core::{fmt::Debug, iter::FusedIterator};
+
+#[cfg(test)]
+mod tests;
+
+const ROOT: usize = 0;
+
+#[derive(Clone, Copy)]
+struct Node {
+    left: usize,
+    right: usize,
+    up: usize,
+    down: usize,
+    column: usize,
+    row: usize,
+}
+
+// node 0 is the root, nodes 1..=columns are the column headers
+#[derive(Clone)]
+pub struct ExactCover {
+    nodes: Vec<Node>,
+    sizes: Vec<usize>,
+    covered: Vec<bool>,
+    rows: Vec<usize>,
+    primary: usize,
+}
+
+pub struct Solutions<'a> {
+    matrix: &'a mut ExactCover,
+    stack: Vec<usize>,
+    descend: bool,
+    done: bool,
+}
+
+impl ExactCover {
+    pub fn new(primary: usize) -> ExactCover {
+        Self::with_secondary(primary, 0)
+    }
+
+    pub fn with_secondary(primary: usize, secondary: usize) -> ExactCover {
+        let columns = primary + secondary;
+        let mut nodes = Vec::with_capacity(columns + 1);
+        for i in 0..=columns {
+            let (left, right) = match i {
+                _ if i > primary => (i, i),
+                ROOT => (primary, if primary == 0 { ROOT } else { 1 }),
+                _ => (i - 1, if i == primary { ROOT } else { i + 1 }),
+            };
+            nodes.push(Node {
+                left,
+                right,
+                up: i,
+                down: i,
+                column: i,
+                row: usize::MAX,
+            });
+        }
+        ExactCover {
+            nodes,
+            sizes: vec![0; columns + 1],
+            covered: vec![false; columns + 1],
+            rows: Vec::new(),
+            primary,
+        }
+    }
+
+    pub fn primary_columns(&self) -> usize {
+        self.primary
+    }
+
+    pub fn columns(&self) -> usize {
+        self.sizes.len() - 1
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        assert!(!columns.is_empty(), "Cannot add an empty row");
+        let mut sorted = columns.to_vec();
+        sorted.sort_unstable();
+        assert!(
+            sorted.windows(2).all(|w| w[0] != w[1]),
+            "Cannot add a row with duplicate columns"
+        );
+        assert!(
+            sorted.last().is_some_and(|&c| c < self.columns()),
+            "Cannot add a row with a nonexistent column"
+        );
+        let row = self.rows.len();
+        let first = self.nodes.len();
+        self.rows.push(first);
+        for (i, &column) in columns.iter().enumerate() {
+            let header = column + 1;
+            let index = self.nodes.len();
+            let up = self.nodes[header].up;
+            self.nodes.push(Node {
+                left: if i == 0 {
+                    first + columns.len() - 1
+                } else {
+                    index - 1
+                },
+                right: if i + 1 == columns.len() {
+                    first
+                } else {
+                    index + 1
+                },
+                up,
+                down: header,
+                column: header,
+                row,
+            });
+            self.nodes[up].down = index;
+            self.nodes[header].up = index;
+            self.sizes[header] += 1;
+        }
+        row
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.rows[row];
+        core::iter::successors(Some(first), move |&i| {
+            Some(self.nodes[i].right).filter(|&next| next != first)
+        })
+        .map(|i| self.nodes[i].column - 1)
+    }
+
+    pub fn is_covered(&self, column: usize) -> bool {
+        self.covered[column + 1]
+    }
+
+    pub fn cover(&mut self, column: usize) {
+        assert!(!self.is_covered(column), "Cannot cover a column twice");
+        self._cover(column + 1);
+    }
+
+    pub fn uncover(&mut self, column: usize) {
+        assert!(
+            self.is_covered(column),
+            "Cannot uncover an uncovered column"
+        );
+        self._uncover(column + 1);
+    }
+
+    pub fn solutions(&mut self) -> Solutions<'_> {
+        Solutions {
+            matrix: self,
+            stack: Vec::new(),
+            descend: true,
+            done: false,
+        }
+    }
+
+    fn _cover(&mut self, header: usize) {
+        self.covered[header] = true;
+        let Node { left, right, .. } = self.nodes[header];
+        self.nodes[left].right = right;
+        self.nodes[right].left = left;
+        let mut i = self.nodes[header].down;
+        while i != header {
+            let mut j = self.nodes[i].right;
+            while j != i {
+                let Node {
+                    up, down, column, ..
+                } = self.nodes[j];
+                self.nodes[up].down = down;
+                self.nodes[down].up = up;
+                self.sizes[column] -= 1;
+                j = self.nodes[j].right;
+            }
+            i = self.nodes[i].down;
+        }
+    }
+
+    fn _uncover(&mut self, header: usize) {
+        let mut i = self.nodes[header].up;
+        while i != header {
+            let mut j = self.nodes[i].left;
+            while j != i {
+                let Node {
+                    up, down, column, ..
+                } = self.nodes[j];
+                self.nodes[up].down = j;
+                self.nodes[down].up = j;
+                self.sizes[column] += 1;
+                j = self.nodes[j].left;
+            }
+            i = self.nodes[i].up;
+        }
+        let Node { left, right, .. } = self.nodes[header];
+        self.nodes[left].right = header;
+        self.nodes[right].left = header;
+        self.covered[header] = false;
+    }
+
+    fn _cover_row_of(&mut self, node: usize) {
+        let mut j = self.nodes[node].right;
+        while j != node {
+            self._cover(self.nodes[j].column);
+            j = self.nodes[j].right;
+        }
+    }
+
+    fn _uncover_row_of(&mut self, node: usize) {
+        let mut j = self.nodes[node].left;
+        while j != node {
+            self._uncover(self.nodes[j].column);
+            j = self.nodes[j].left;
+        }
+    }
+
+    fn _smallest_column(&self) -> Option<usize> {
+        let mut best = None;
+        let mut header = self.nodes[ROOT].right;
+        while header != ROOT {
+            if best.is_none_or(|best| self.sizes[header] < self.sizes[best]) {
+                best = Some(header);
+            }
+            header = self.nodes[header].right;
+        }
+        best
+    }
+}
+
+impl Debug for ExactCover {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExactCover")
+            .field("primary", &self.primary)
+            .field("columns", &self.columns())
+            .field("rows", &self.rows())
+            .finish()
+    }
+}
+
+// Solutions
+
+impl Solutions<'_> {
+    fn _solution(&self) -> Vec<usize> {
+        self.stack
+            .iter()
+            .map(|&node| self.matrix.nodes[node].row)
+            .collect()
+    }
+}
+
+impl Debug for Solutions<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Solutions")
+            .field(&self.matrix)
+            .field(&self._solution())
+            .finish()
+    }
+}
+
+impl Drop for Solutions<'_> {
+    fn drop(&mut self) {
+        while let Some(node) = self.stack.pop() {
+            self.matrix._uncover_row_of(node);
+            self.matrix._uncover(self.matrix.nodes[node].column);
+        }
+    }
+}
+
+impl FusedIterator for Solutions<'_> {}
+
+impl Iterator for Solutions<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.descend {
+                let Some(header) = self.matrix._smallest_column() else {
+                    self.descend = false;
+                    return Some(self._solution());
+                };
+                let node = self.matrix.nodes[header].down;
+                if node == header {
+                    self.descend = false;
+                    continue;
+                }
+                self.matrix._cover(header);
+                self.matrix._cover_row_of(node);
+                self.stack.push(node);
+            } else if let Some(node) = self.stack.pop() {
+                self.matrix._uncover_row_of(node);
+                let next = self.matrix.nodes[node].down;
+                let header = self.matrix.nodes[node].column;
+                if next == header {
+                    self.matrix._uncover(header);
+                    continue;
+                }
+                self.matrix._cover_row_of(next);
+                self.stack.push(next);
+                self.descend = true;
+            } else {
+                self.done = true;
+            }
+        }
+        None
+    }
+}
+
+pub fn solve_sudoku(grid: &[[u8; 9]; 9]) -> Option<[[u8; 9]; 9]> {
+    // columns: cell, row-digit, column-digit, box-digit
+    let mut matrix = ExactCover::new(4 * 81);
+    for r in 0..9 {
+        for c in 0..9 {
+            for d in 0..9 {
+                let b = r / 3 * 3 + c / 3;
+                matrix.add_row(&[r * 9 + c, 81 + r * 9 + d, 162 + c * 9 + d, 243 + b * 9 + d]);
+            }
+        }
+    }
+    let mut givens = Vec::new();
+    for (r, cells) in grid.iter().enumerate() {
+        for (c, &digit) in cells.iter().enumerate() {
+            if digit == 0 {
+                continue;
+            }
+            assert!(
+                digit <= 9,
+                "Cannot solve a sudoku containing digits above 9"
+            );
+            let row = (r * 9 + c) * 9 + digit as usize - 1;
+            let columns: Vec<_> = matrix.row(row).collect();
+            if columns.iter().any(|&column| matrix.is_covered(column)) {
+                return None;
+            }
+            columns.iter().for_each(|&column| matrix.cover(column));
+            givens.push(row);
+        }
+    }
+    let solution = matrix.solutions().next()?;
+    let mut solved = [[0; 9]; 9];
+    for row in givens.into_iter().chain(solution) {
+        let (cell, digit) = (row / 9, row % 9);
+        solved[cell / 9][cell % 9] = digit as u8 + 1;
+    }
+    Some(solved)
+}
+
+pub fn n_queens(n: usize) -> Vec<Vec<usize>> {
+    // primary columns: ranks, files; secondary columns: both diagonal directions
+    let diagonals = (2 * n).saturating_sub(1);
+    let mut matrix = ExactCover::with_secondary(2 * n, 2 * diagonals);
+    for rank in 0..n {
+        for file in 0..n {
+            matrix.add_row(&[
+                rank,
+                n + file,
+                2 * n + rank + file,
+                2 * n + diagonals + n - 1 + rank - file,
+            ]);
+        }
+    }
+    matrix
+        .solutions()
+        .map(|rows| {
+            let mut files = vec![0; n];
+            rows.into_iter().for_each(|row| files[row / n] = row % n);
+            files
+        })
+        .collect()
+}
@@ -0,0 +1,185 @@
+use super::*;
+
+fn knuth_example() -> ExactCover {
+    let mut matrix = ExactCover::new(7);
+    matrix.add_row(&[2, 4, 5]);
+    matrix.add_row(&[0, 3, 6]);
+    matrix.add_row(&[1, 2, 5]);
+    matrix.add_row(&[0, 3]);
+    matrix.add_row(&[1, 6]);
+    matrix.add_row(&[3, 4, 6]);
+    matrix
+}
+
+#[test]
+fn test_knuth_example() {
+    let mut matrix = knuth_example();
+    let mut solutions: Vec<_> = matrix.solutions().collect();
+    assert_eq!(solutions.len(), 1);
+    solutions[0].sort();
+    assert_eq!(solutions[0], [0, 3, 4]);
+}
+
+#[test]
+fn test_no_solution() {
+    let mut matrix = ExactCover::new(3);
+    matrix.add_row(&[0, 1]);
+    matrix.add_row(&[1, 2]);
+    assert_eq!(matrix.solutions().next(), None);
+    let mut matrix = ExactCover::new(1);
+    assert_eq!(matrix.solutions().next(), None);
+}
+
+#[test]
+fn test_empty() {
+    let mut matrix = ExactCover::new(0);
+    assert_eq!(matrix.solutions().collect::<Vec<_>>(), [vec![]]);
+}
+
+#[test]
+fn test_multiple_solutions() {
+    let mut matrix = ExactCover::new(2);
+    matrix.add_row(&[0]);
+    matrix.add_row(&[1]);
+    matrix.add_row(&[0, 1]);
+    matrix.add_row(&[1, 0]);
+    let mut solutions: Vec<_> = matrix
+        .solutions()
+        .map(|mut rows| {
+            rows.sort();
+            rows
+        })
+        .collect();
+    solutions.sort();
+    assert_eq!(solutions, [vec![0, 1], vec![2], vec![3]]);
+}
+
+#[test]
+fn test_secondary_columns() {
+    let mut matrix = ExactCover::with_secondary(2, 1);
+    matrix.add_row(&[0, 2]);
+    matrix.add_row(&[1, 2]);
+    matrix.add_row(&[0]);
+    matrix.add_row(&[1]);
+    let mut solutions: Vec<_> = matrix
+        .solutions()
+        .map(|mut rows| {
+            rows.sort();
+            rows
+        })
+        .collect();
+    solutions.sort();
+    assert_eq!(solutions, [vec![0, 3], vec![1, 2], vec![2, 3]]);
+}
+
+#[test]
+fn test_row() {
+    let matrix = knuth_example();
+    assert_eq!(matrix.row(0).collect::<Vec<_>>(), [2, 4, 5]);
+    assert_eq!(matrix.row(4).collect::<Vec<_>>(), [1, 6]);
+    assert_eq!(matrix.rows(), 6);
+    assert_eq!(matrix.columns(), 7);
+}
+
+#[test]
+fn test_cover_uncover() {
+    let mut matrix = knuth_example();
+    matrix.cover(0);
+    assert!(matrix.is_covered(0));
+    assert_eq!(matrix.solutions().collect::<Vec<_>>(), [vec![5, 2]]);
+    matrix.uncover(0);
+    assert!(!matrix.is_covered(0));
+    assert_eq!(matrix.solutions().count(), 1);
+
+    // covering the columns of a row selects it
+    for column in [2, 4, 5] {
+        matrix.cover(column);
+    }
+    let mut solutions: Vec<_> = matrix.solutions().collect();
+    solutions[0].sort();
+    assert_eq!(solutions, [vec![3, 4]]);
+    for column in [5, 4, 2] {
+        matrix.uncover(column);
+    }
+    assert_eq!(matrix.solutions().count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_cover_twice() {
+    let mut matrix = knuth_example();
+    matrix.cover(3);
+    matrix.cover(3);
+}
+
+#[test]
+fn test_restored_after_partial_iteration() {
+    let mut matrix = ExactCover::new(4);
+    for a in 0..4 {
+        for b in a + 1..4 {
+            matrix.add_row(&[a, b]);
+        }
+        matrix.add_row(&[a]);
+    }
+    let count = matrix.solutions().count();
+    assert_eq!(count, 10); // partitions of 4 elements into blocks of at most 2
+    let mut solutions = matrix.solutions();
+    solutions.next();
+    solutions.next();
+    drop(solutions);
+    assert_eq!(matrix.solutions().count(), count);
+}
+
+#[test]
+fn test_sudoku() {
+    let grid = [
+        [5, 3, 0, 0, 7, 0, 0, 0, 0],
+        [6, 0, 0, 1, 9, 5, 0, 0, 0],
+        [0, 9, 8, 0, 0, 0, 0, 6, 0],
+        [8, 0, 0, 0, 6, 0, 0, 0, 3],
+        [4, 0, 0, 8, 0, 3, 0, 0, 1],
+        [7, 0, 0, 0, 2, 0, 0, 0, 6],
+        [0, 6, 0, 0, 0, 0, 2, 8, 0],
+        [0, 0, 0, 4, 1, 9, 0, 0, 5],
+        [0, 0, 0, 0, 8, 0, 0, 7, 9],
+    ];
+    let solved = [
+        [5, 3, 4, 6, 7, 8, 9, 1, 2],
+        [6, 7, 2, 1, 9, 5, 3, 4, 8],
+        [1, 9, 8, 3, 4, 2, 5, 6, 7],
+        [8, 5, 9, 7, 6, 1, 4, 2, 3],
+        [4, 2, 6, 8, 5, 3, 7, 9, 1],
+        [7, 1, 3, 9, 2, 4, 8, 5, 6],
+        [9, 6, 1, 5, 3, 7, 2, 8, 4],
+        [2, 8, 7, 4, 1, 9, 6, 3, 5],
+        [3, 4, 5, 2, 8, 6, 1, 7, 9],
+    ];
+    assert_eq!(solve_sudoku(&grid), Some(solved));
+    assert_eq!(solve_sudoku(&solved), Some(solved));
+    let mut invalid = grid;
+    invalid[0][2] = 5;
+    assert_eq!(solve_sudoku(&invalid), None);
+    let empty = solve_sudoku(&[[0; 9]; 9]).unwrap();
+    for i in 0..9 {
+        let mut row: Vec<_> = empty[i].to_vec();
+        let mut column: Vec<_> = empty.iter().map(|row| row[i]).collect();
+        row.sort();
+        column.sort();
+        assert_eq!(row, (1..=9).collect::<Vec<_>>());
+        assert_eq!(column, (1..=9).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_n_queens() {
+    let counts: Vec<_> = (1..=8).map(|n| n_queens(n).len()).collect();
+    assert_eq!(counts, [1, 0, 0, 2, 10, 4, 40, 92]);
+    for files in n_queens(6) {
+        for (a, &fa) in files.iter().enumerate() {
+            for (b, &fb) in files.iter().enumerate().skip(a + 1) {
+                assert_ne!(fa, fb);
+                assert_ne!(b - a, fa.abs_diff(fb));
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::io::{self, Read, Write};
+
+use crate::linked_list::LinkedList;
+
+#[cfg(test)]
+mod tests;
+
+pub const VERSION: u8 = 2;
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+pub trait Encode {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()>;
+}
+
+pub trait Decode: Sized {
+    fn decode(r: &mut dyn Read) -> io::Result<Self>;
+}
+
+struct Checksummed<S> {
+    inner: S,
+    crc: u32,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn decode_len(r: &mut dyn Read) -> io::Result<usize> {
+    usize::try_from(u64::decode(r)?).map_err(|_| invalid_data("length does not fit in usize"))
+}
+
+impl<S> Checksummed<S> {
+    fn new(inner: S) -> Checksummed<S> {
+        Checksummed {
+            inner,
+            crc: u32::MAX,
+        }
+    }
+
+    fn _update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.crc = CRC_TABLE[((self.crc ^ byte as u32) & 0xFF) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    fn checksum(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl<W: Write> Write for Checksummed<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self._update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<R: Read> Read for Checksummed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self._update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub fn write_to<W, F>(&self, w: W, mut enc: F) -> io::Result<()>
+    where
+        W: Write,
+        F: FnMut(&T, &mut dyn Write) -> io::Result<()>,
+    {
+        let mut w = Checksummed::new(w);
+        w.write_all(&[VERSION])?;
+        (self.len() as u64).encode(&mut w)?;
+        let header = w.checksum();
+        w.inner.write_all(&header.to_le_bytes())?;
+        for value in self {
+            enc(value, &mut w)?;
+        }
+        let checksum = w.checksum();
+        w.inner.write_all(&checksum.to_le_bytes())?;
+        w.flush()
+    }
+
+    pub fn read_from<R, F>(r: R, mut dec: F) -> io::Result<LinkedList<T>>
+    where
+        R: Read,
+        F: FnMut(&mut dyn Read) -> io::Result<T>,
+    {
+        let mut r = Checksummed::new(r);
+        let version = u8::decode(&mut r)?;
+        if version != VERSION {
+            return Err(invalid_data("unsupported format version"));
+        }
+        let len = u64::decode(&mut r)?;
+        // the length drives the decode loop, so it is verified before any
+        // element is read rather than only by the trailing checksum
+        let header = r.checksum();
+        if u32::decode(&mut r.inner)? != header {
+            return Err(invalid_data("header checksum mismatch"));
+        }
+        let mut list = LinkedList::new();
+        for _ in 0..len {
+            list.push_back(dec(&mut r)?);
+        }
+        let checksum = r.checksum();
+        if u32::decode(&mut r.inner)? != checksum {
+            return Err(invalid_data("checksum mismatch"));
+        }
+        Ok(list)
+    }
+}
+
+macro_rules! impl_int {
+    ($($int:ty),*) => {$(
+        impl Encode for $int {
+            fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+                w.write_all(&self.to_le_bytes())
+            }
+        }
+
+        impl Decode for $int {
+            fn decode(r: &mut dyn Read) -> io::Result<Self> {
+                let mut bytes = [0; std::mem::size_of::<$int>()];
+                r.read_exact(&mut bytes)?;
+                Ok(<$int>::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+impl_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Encode for usize {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as u64).encode(w)
+    }
+}
+
+impl Decode for usize {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        decode_len(r)
+    }
+}
+
+impl Encode for isize {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as i64).encode(w)
+    }
+}
+
+impl Decode for isize {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        isize::try_from(i64::decode(r)?).map_err(|_| invalid_data("value does not fit in isize"))
+    }
+}
+
+impl Encode for f32 {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.to_bits().encode(w)
+    }
+}
+
+impl Decode for f32 {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        u32::decode(r).map(f32::from_bits)
+    }
+}
+
+impl Encode for f64 {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.to_bits().encode(w)
+    }
+}
+
+impl Decode for f64 {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        u64::decode(r).map(f64::from_bits)
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as u8).encode(w)
+    }
+}
+
+impl Decode for bool {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        match u8::decode(r)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("invalid bool")),
+        }
+    }
+}
+
+impl Encode for char {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        (*self as u32).encode(w)
+    }
+}
+
+impl Decode for char {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        char::from_u32(u32::decode(r)?).ok_or_else(|| invalid_data("invalid char"))
+    }
+}
+
+impl Encode for str {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.len().encode(w)?;
+        w.write_all(self.as_bytes())
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.as_str().encode(w)
+    }
+}
+
+impl Decode for String {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        let len = decode_len(r)?;
+        // grows with the bytes actually read, never with the claimed length
+        let mut bytes = Vec::new();
+        if r.take(len as u64).read_to_end(&mut bytes)? != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(bytes).map_err(|_| invalid_data("invalid utf-8"))
+    }
+}
+
+impl<T: Encode + ?Sized> Encode for &T {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        (**self).encode(w)
+    }
+}
+
+impl<T: Encode> Encode for LinkedList<T> {
+    fn encode(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.len().encode(w)?;
+        self.iter().try_for_each(|value| value.encode(w))
+    }
+}
+
+impl<T: Decode> Decode for LinkedList<T> {
+    fn decode(r: &mut dyn Read) -> io::Result<Self> {
+        let len = decode_len(r)?;
+        let mut list = LinkedList::new();
+        for _ in 0..len {
+            list.push_back(T::decode(r)?);
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,146 @@
+use super::*;
+use crate::test_util::*;
+
+fn encode_list<T: Encode>(list: &LinkedList<T>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    list.write_to(&mut bytes, T::encode).unwrap();
+    bytes
+}
+
+fn decode_list<T: Decode>(bytes: &[u8]) -> io::Result<LinkedList<T>> {
+    LinkedList::read_from(bytes, T::decode)
+}
+
+#[test]
+fn test_round_trip() {
+    let ints: LinkedList<i64> = LinkedList::from([0, -1, i64::MIN, i64::MAX]);
+    assert_eq!(decode_list::<i64>(&encode_list(&ints)).unwrap(), ints);
+    let mixed = LinkedList::from([(1u8, 'é'), (255, '\u{10FFFF}')]);
+    let mut bytes = Vec::new();
+    mixed
+        .write_to(&mut bytes, |(byte, c), w| {
+            byte.encode(w)?;
+            c.encode(w)
+        })
+        .unwrap();
+    let decoded =
+        LinkedList::read_from(&bytes[..], |r| Ok((u8::decode(r)?, char::decode(r)?))).unwrap();
+    assert_eq!(decoded, mixed);
+    let strings = LinkedList::from([String::new(), "héllo".to_string(), "🦀".repeat(100)]);
+    assert_eq!(
+        decode_list::<String>(&encode_list(&strings)).unwrap(),
+        strings
+    );
+    let nested: LinkedList<LinkedList<bool>> =
+        LinkedList::from([LinkedList::new(), [true].into(), [false, true].into()]);
+    assert_eq!(
+        decode_list::<LinkedList<bool>>(&encode_list(&nested)).unwrap(),
+        nested
+    );
+    let floats = LinkedList::from([0.5, -0.0, f64::INFINITY, f64::NAN]);
+    let decoded = decode_list::<f64>(&encode_list(&floats)).unwrap();
+    assert!(iter_eq_bits(&decoded, &floats));
+    let sizes = LinkedList::from([(usize::MAX >> 1) as isize, isize::MIN]);
+    assert_eq!(decode_list::<isize>(&encode_list(&sizes)).unwrap(), sizes);
+    let empty = LinkedList::<u128>::new();
+    assert_eq!(encode_list(&empty).len(), 1 + 8 + 4 + 4);
+    assert!(decode_list::<u128>(&encode_list(&empty))
+        .unwrap()
+        .is_empty());
+}
+
+fn iter_eq_bits(left: &LinkedList<f64>, right: &LinkedList<f64>) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(l, r)| l.to_bits() == r.to_bits())
+}
+
+#[test]
+fn test_format() {
+    let bytes = encode_list(&LinkedList::from([1u16, 2]));
+    assert_eq!(bytes[0], VERSION);
+    assert_eq!(&bytes[1..9], &2u64.to_le_bytes());
+    assert_eq!(&bytes[13..17], &[1, 0, 2, 0]);
+    assert_eq!(bytes.len(), 21);
+    let mut reader = &bytes[..];
+    let mut trailing = bytes.clone();
+    trailing.push(42);
+    assert_eq!(
+        LinkedList::read_from(&mut reader, u16::decode).unwrap(),
+        LinkedList::from([1, 2])
+    );
+    assert!(reader.is_empty());
+    let mut reader = &trailing[..];
+    assert!(LinkedList::read_from(&mut reader, u16::decode).is_ok());
+    assert_eq!(reader, [42]);
+}
+
+#[test]
+fn test_corrupt_input() {
+    let list = LinkedList::from([
+        LinkedList::from(["a".to_string(), "bc".to_string()]),
+        LinkedList::new(),
+        LinkedList::from(["def".to_string()]),
+    ]);
+    let bytes = encode_list(&list);
+    for len in 0..bytes.len() {
+        let err = decode_list::<LinkedList<String>>(&bytes[..len]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+    for i in 0..bytes.len() {
+        for flip in [0x01, 0x80, 0xFF] {
+            let mut corrupt = bytes.clone();
+            corrupt[i] ^= flip;
+            assert!(decode_list::<LinkedList<String>>(&corrupt).is_err());
+        }
+    }
+    let mut version = bytes.clone();
+    version[0] = VERSION + 1;
+    let err = decode_list::<LinkedList<String>>(&version).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_corrupt_length() {
+    let mut bytes = encode_list(&LinkedList::from([1u8, 2, 3]));
+    bytes[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+    let mut decoded = 0;
+    let err = LinkedList::read_from(&bytes[..], |_| {
+        decoded += 1;
+        Ok(())
+    })
+    .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(decoded, 0);
+}
+
+#[test]
+fn test_invalid_values() {
+    fn rewrite(payload: &[u8]) -> Vec<u8> {
+        let mut w = Checksummed::new(Vec::new());
+        w.write_all(&[VERSION]).unwrap();
+        w.write_all(&1u64.to_le_bytes()).unwrap();
+        let header = w.checksum();
+        w.inner.write_all(&header.to_le_bytes()).unwrap();
+        w.write_all(payload).unwrap();
+        let checksum = w.checksum();
+        let mut bytes = w.inner;
+        bytes.extend(checksum.to_le_bytes());
+        bytes
+    }
+
+    assert_eq!(
+        decode_list::<bool>(&rewrite(&[1])).unwrap(),
+        LinkedList::from([true])
+    );
+    assert!(decode_list::<bool>(&rewrite(&[2])).is_err());
+    assert!(decode_list::<char>(&rewrite(&0xD800u32.to_le_bytes())).is_err());
+    let mut invalid_utf8 = 2u64.to_le_bytes().to_vec();
+    invalid_utf8.extend([0xC3, 0x28]);
+    assert!(decode_list::<String>(&rewrite(&invalid_utf8)).is_err());
+    let huge = u64::MAX.to_le_bytes();
+    assert!(decode_list::<String>(&rewrite(&huge)).is_err());
+    assert!(decode_list::<LinkedList<u8>>(&rewrite(&huge)).is_err());
+}
@@ -0,0 +1,205 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::linked_list::{LinkedList, NodePtr};
+
+use super::{cut, drop_trees, link, singleton, take_front, Entry, Handle, Slab, StaleHandle};
+
+pub struct FibonacciHeap<T> {
+    roots: LinkedList<Entry<T>>,
+    min: NodePtr<Entry<T>>,
+    slab: Slab<T>,
+    len: usize,
+}
+
+impl<T> FibonacciHeap<T> {
+    pub fn new() -> FibonacciHeap<T> {
+        FibonacciHeap {
+            roots: LinkedList::new(),
+            min: Default::default(),
+            slab: Slab::new(),
+            len: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn clear(&mut self) {
+        self.min = Default::default();
+        let trees = core::mem::take(&mut self.roots);
+        self.slab.clear();
+        self.len = 0;
+        drop_trees(trees);
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.min.as_ref().map(|node| &node.value.value)
+    }
+
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.slab.get(handle).is_some()
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.slab
+            .get(handle)
+            .map(|node| &node.as_mut_unchecked().value.value)
+    }
+}
+
+impl<T: Ord> FibonacciHeap<T> {
+    fn _update_min(&mut self, node: NodePtr<Entry<T>>) {
+        let is_min = self
+            .peek()
+            .is_none_or(|min| node.as_mut_unchecked().value.value < *min);
+        if is_min {
+            self.min = node;
+        }
+    }
+
+    fn _cut_to_roots(&mut self, node: NodePtr<Entry<T>>) {
+        let mut parent = node.as_mut_unchecked().value.parent;
+        self.roots.append(&mut cut(node));
+        while let Some(parent_node) = parent.as_mut() {
+            let entry = &mut parent_node.value;
+            if entry.parent.ptr.is_none() {
+                break;
+            } else if !entry.marked {
+                entry.marked = true;
+                break;
+            }
+            let grandparent = entry.parent;
+            self.roots.append(&mut cut(parent));
+            parent = grandparent;
+        }
+    }
+
+    fn _consolidate(&mut self) {
+        let mut by_degree: Vec<LinkedList<Entry<T>>> = Vec::new();
+        while let Some(mut tree) = take_front(&mut self.roots) {
+            loop {
+                let degree = tree.head.as_mut_unchecked().value.children.len();
+                if degree >= by_degree.len() {
+                    by_degree.resize_with(degree + 1, Default::default);
+                }
+                let other = core::mem::take(&mut by_degree[degree]);
+                if other.is_empty() {
+                    by_degree[degree] = tree;
+                    break;
+                }
+                tree = link(tree, other);
+            }
+        }
+        self.min = Default::default();
+        for mut tree in by_degree {
+            if !tree.is_empty() {
+                self._update_min(tree.head);
+                self.roots.append(&mut tree);
+            }
+        }
+    }
+
+    pub fn push(&mut self, value: T) -> Handle {
+        let mut tree = singleton(value);
+        let handle = self.slab.insert(tree.head);
+        self._update_min(tree.head);
+        self.roots.append(&mut tree);
+        self.len += 1;
+        handle
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.min.ptr?;
+        let mut entry = self
+            .roots
+            ._unlink_as_list(core::mem::take(&mut self.min))
+            .pop_front()?;
+        self.slab.release(entry.key);
+        self.len -= 1;
+        while let Some(mut child) = take_front(&mut entry.children) {
+            child.head.as_mut_unchecked().value.marked = false;
+            self.roots.append(&mut child);
+        }
+        self._consolidate();
+        Some(entry.value)
+    }
+
+    pub fn meld(&mut self, other: &mut FibonacciHeap<T>) {
+        let other_min = core::mem::take(&mut other.min);
+        if other_min.ptr.is_some() {
+            self._update_min(other_min);
+        }
+        self.roots.append(&mut other.roots);
+        self.slab.absorb(&mut other.slab);
+        self.len += core::mem::take(&mut other.len);
+    }
+
+    pub fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), StaleHandle> {
+        let node = self.slab.get(handle).ok_or(StaleHandle)?;
+        let entry = &mut node.as_mut_unchecked().value;
+        assert!(
+            value <= entry.value,
+            "Cannot decrease a key to a greater value"
+        );
+        entry.value = value;
+        if let Some(parent) = entry.parent.as_ref() {
+            if entry.value < parent.value.value {
+                self._cut_to_roots(node);
+            }
+        }
+        self._update_min(node);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let node = self.slab.get(handle)?;
+        if node.as_mut_unchecked().value.parent.ptr.is_some() {
+            self._cut_to_roots(node);
+        }
+        self.min = node;
+        self.pop()
+    }
+}
+
+impl<T: Debug> Debug for FibonacciHeap<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FibonacciHeap")
+            .field("len", &self.len)
+            .field("peek", &self.peek())
+            .finish()
+    }
+}
+
+impl<T> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for FibonacciHeap<T> {
+    fn drop(&mut self) {
+        drop_trees(core::mem::take(&mut self.roots));
+    }
+}
+
+impl<T: Ord> Extend<T> for FibonacciHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elt| {
+            self.push(elt);
+        });
+    }
+}
+
+impl<T: Ord> FromIterator<T> for FibonacciHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::linked_list::{LinkedList, NodePtr};
+
+mod fibonacci;
+mod pairing;
+
+#[cfg(test)]
+mod tests;
+
+pub use fibonacci::FibonacciHeap;
+pub use pairing::PairingHeap;
+
+struct Entry<T> {
+    value: T,
+    key: usize,
+    parent: NodePtr<Entry<T>>,
+    children: LinkedList<Entry<T>>,
+    marked: bool,
+}
+
+struct Slot<T> {
+    generation: u64,
+    node: NodePtr<Entry<T>>,
+}
+
+// a slot's generation moves on whenever its element leaves the heap, so
+// handles to removed elements never resolve again
+struct Slab<T> {
+    slots: Vec<Slot<T>>,
+    vacant: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle {
+    key: usize,
+    generation: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaleHandle;
+
+impl<T> Slab<T> {
+    fn new() -> Slab<T> {
+        Slab {
+            slots: Vec::new(),
+            vacant: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, node: NodePtr<Entry<T>>) -> Handle {
+        let key = self.vacant.pop().unwrap_or_else(|| {
+            self.slots.push(Slot {
+                generation: 0,
+                node: Default::default(),
+            });
+            self.slots.len() - 1
+        });
+        node.as_mut_unchecked().value.key = key;
+        self.slots[key].node = node;
+        Handle {
+            key,
+            generation: self.slots[key].generation,
+        }
+    }
+
+    fn get(&self, handle: Handle) -> Option<NodePtr<Entry<T>>> {
+        self.slots
+            .get(handle.key)
+            .filter(|slot| slot.generation == handle.generation && slot.node.ptr.is_some())
+            .map(|slot| slot.node)
+    }
+
+    fn release(&mut self, key: usize) {
+        let slot = &mut self.slots[key];
+        slot.node = Default::default();
+        slot.generation += 1;
+        self.vacant.push(key);
+    }
+
+    fn clear(&mut self) {
+        for key in 0..self.slots.len() {
+            if self.slots[key].node.ptr.is_some() {
+                self.release(key);
+            }
+        }
+    }
+
+    // moves the live elements of `other` over; its handles do not carry over
+    fn absorb(&mut self, other: &mut Slab<T>) {
+        for slot in &mut other.slots {
+            let node = core::mem::take(&mut slot.node);
+            if node.ptr.is_some() {
+                slot.generation += 1;
+                self.insert(node);
+            }
+        }
+        other.vacant = (0..other.slots.len()).collect();
+    }
+}
+
+impl Display for StaleHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("handle does not refer to an element of this heap")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StaleHandle {}
+
+fn singleton<T>(value: T) -> LinkedList<Entry<T>> {
+    LinkedList::from([Entry {
+        value,
+        key: 0,
+        parent: Default::default(),
+        children: LinkedList::new(),
+        marked: false,
+    }])
+}
+
+fn take_front<T>(list: &mut LinkedList<Entry<T>>) -> Option<LinkedList<Entry<T>>> {
+    let tree = list.cursor_front_mut().remove_current_as_list()?;
+    tree.head.as_mut_unchecked().value.parent = Default::default();
+    Some(tree)
+}
+
+fn take_back<T>(list: &mut LinkedList<Entry<T>>) -> Option<LinkedList<Entry<T>>> {
+    let tree = list.cursor_back_mut().remove_current_as_list()?;
+    tree.head.as_mut_unchecked().value.parent = Default::default();
+    Some(tree)
+}
+
+// both lists hold at most one root, the loser becomes the first child of the winner
+fn link<T: Ord>(mut a: LinkedList<Entry<T>>, mut b: LinkedList<Entry<T>>) -> LinkedList<Entry<T>> {
+    match (a.front(), b.front()) {
+        (None, _) => return b,
+        (_, None) => return a,
+        (Some(x), Some(y)) if y.value < x.value => core::mem::swap(&mut a, &mut b),
+        _ => (),
+    }
+    let loser = &mut b.head.as_mut_unchecked().value;
+    loser.parent = a.head;
+    loser.marked = false;
+    let winner = &mut a.head.as_mut_unchecked().value;
+    b.append(&mut winner.children);
+    winner.children = b;
+    a
+}
+
+// unlinks a non-root node from its parent's children
+fn cut<T>(node: NodePtr<Entry<T>>) -> LinkedList<Entry<T>> {
+    let entry = &mut node.as_mut_unchecked().value;
+    let parent = core::mem::take(&mut entry.parent);
+    entry.marked = false;
+    parent
+        .as_mut_unchecked()
+        .value
+        .children
+        ._unlink_as_list(node)
+}
+
+// drops iteratively since the trees can be arbitrarily deep
+fn drop_trees<T>(mut trees: LinkedList<Entry<T>>) {
+    while let Some(mut entry) = trees.pop_front() {
+        trees.append(&mut entry.children);
+    }
+}
@@ -0,0 +1,155 @@
+use core::fmt::Debug;
+
+use crate::linked_list::LinkedList;
+
+use super::{
+    cut, drop_trees, link, singleton, take_back, take_front, Entry, Handle, Slab, StaleHandle,
+};
+
+pub struct PairingHeap<T> {
+    root: LinkedList<Entry<T>>,
+    slab: Slab<T>,
+    len: usize,
+}
+
+fn merge_pairs<T: Ord>(mut trees: LinkedList<Entry<T>>) -> LinkedList<Entry<T>> {
+    let mut pairs = LinkedList::new();
+    while let Some(first) = take_front(&mut trees) {
+        let second = take_front(&mut trees).unwrap_or_default();
+        pairs.append(&mut link(first, second));
+    }
+    let mut root = LinkedList::new();
+    while let Some(last) = take_back(&mut pairs) {
+        root = link(last, root);
+    }
+    root
+}
+
+impl<T> PairingHeap<T> {
+    pub fn new() -> PairingHeap<T> {
+        PairingHeap {
+            root: LinkedList::new(),
+            slab: Slab::new(),
+            len: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn clear(&mut self) {
+        let trees = core::mem::take(&mut self.root);
+        self.slab.clear();
+        self.len = 0;
+        drop_trees(trees);
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.root.front().map(|entry| &entry.value)
+    }
+
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.slab.get(handle).is_some()
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.slab
+            .get(handle)
+            .map(|node| &node.as_mut_unchecked().value.value)
+    }
+}
+
+impl<T: Ord> PairingHeap<T> {
+    pub fn push(&mut self, value: T) -> Handle {
+        let tree = singleton(value);
+        let handle = self.slab.insert(tree.head);
+        self.root = link(core::mem::take(&mut self.root), tree);
+        self.len += 1;
+        handle
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let entry = self.root.pop_front()?;
+        self.slab.release(entry.key);
+        self.len -= 1;
+        self.root = merge_pairs(entry.children);
+        Some(entry.value)
+    }
+
+    pub fn meld(&mut self, other: &mut PairingHeap<T>) {
+        self.slab.absorb(&mut other.slab);
+        self.len += core::mem::take(&mut other.len);
+        self.root = link(
+            core::mem::take(&mut self.root),
+            core::mem::take(&mut other.root),
+        );
+    }
+
+    pub fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), StaleHandle> {
+        let node = self.slab.get(handle).ok_or(StaleHandle)?;
+        let entry = &mut node.as_mut_unchecked().value;
+        assert!(
+            value <= entry.value,
+            "Cannot decrease a key to a greater value"
+        );
+        entry.value = value;
+        if entry.parent.ptr.is_some() {
+            self.root = link(core::mem::take(&mut self.root), cut(node));
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let node = self.slab.get(handle)?;
+        if node.as_mut_unchecked().value.parent.ptr.is_none() {
+            return self.pop();
+        }
+        let entry = cut(node).pop_front()?;
+        self.slab.release(entry.key);
+        self.len -= 1;
+        self.root = link(core::mem::take(&mut self.root), merge_pairs(entry.children));
+        Some(entry.value)
+    }
+}
+
+impl<T: Debug> Debug for PairingHeap<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PairingHeap")
+            .field("len", &self.len)
+            .field("peek", &self.peek())
+            .finish()
+    }
+}
+
+impl<T> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for PairingHeap<T> {
+    fn drop(&mut self) {
+        drop_trees(core::mem::take(&mut self.root));
+    }
+}
+
+impl<T: Ord> Extend<T> for PairingHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elt| {
+            self.push(elt);
+        });
+    }
+}
+
+impl<T: Ord> FromIterator<T> for PairingHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
@@ -0,0 +1,233 @@
+use super::*;
+use crate::test_util::*;
+
+macro_rules! heap_tests {
+    ($module:ident, $heap:ident) => {
+        mod $module {
+            use super::*;
+
+            #[test]
+            fn test_empty() {
+                let mut heap = $heap::<i32>::new();
+                assert!(heap.is_empty());
+                assert_eq!(heap.peek(), None);
+                assert_eq!(heap.pop(), None);
+                assert_eq!(heap.len(), 0);
+            }
+
+            #[test]
+            fn test_push_pop_sorted() {
+                let mut seed = 42;
+                let mut values: Vec<_> =
+                    (0..1000).map(|_| pseudo_random(&mut seed) % 100).collect();
+                let mut heap: $heap<_> = values.iter().copied().collect();
+                assert_eq!(heap.len(), values.len());
+                values.sort();
+                for value in values {
+                    assert_eq!(heap.peek(), Some(&value));
+                    assert_eq!(heap.pop(), Some(value));
+                }
+                assert!(heap.is_empty());
+            }
+
+            #[test]
+            fn test_meld() {
+                let mut heap: $heap<_> = [5, 1, 9].into_iter().collect();
+                let mut other: $heap<_> = [4, 0, 7].into_iter().collect();
+                heap.meld(&mut other);
+                assert!(other.is_empty());
+                assert_eq!(other.peek(), None);
+                assert_eq!(heap.len(), 6);
+                heap.meld(&mut other);
+                assert_eq!(heap.len(), 6);
+                other.meld(&mut heap);
+                assert_eq!(other.len(), 6);
+                let popped: Vec<_> = std::iter::from_fn(|| other.pop()).collect();
+                assert_eq!(popped, [0, 1, 4, 5, 7, 9]);
+            }
+
+            #[test]
+            fn test_decrease_key() {
+                let mut heap = $heap::new();
+                let handles: Vec<_> = (0..100).map(|i| heap.push(i + 100)).collect();
+                assert_eq!(heap.pop(), Some(100));
+                for (i, &handle) in handles.iter().enumerate().skip(1) {
+                    if i % 3 == 0 {
+                        assert_eq!(heap.decrease_key(handle, 100 - i), Ok(()));
+                        assert_eq!(heap.get(handle), Some(&(100 - i)));
+                    }
+                }
+                let mut expected: Vec<_> = (1..100)
+                    .map(|i| if i % 3 == 0 { 100 - i } else { i + 100 })
+                    .collect();
+                expected.sort();
+                let popped: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+                assert_eq!(popped, expected);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_increase_key() {
+                let mut heap = $heap::new();
+                let handle = heap.push(1);
+                let _ = heap.decrease_key(handle, 2);
+            }
+
+            #[test]
+            fn test_remove() {
+                let mut heap = $heap::new();
+                let handles: Vec<_> = (0..50).map(|i| heap.push(i)).collect();
+                assert_eq!(heap.pop(), Some(0));
+                for &handle in handles.iter().skip(1).step_by(2) {
+                    let value = *heap.get(handle).unwrap();
+                    assert_eq!(heap.remove(handle), Some(value));
+                }
+                assert_eq!(heap.len(), 24);
+                let popped: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+                assert_eq!(popped, (2..50).step_by(2).collect::<Vec<_>>());
+            }
+
+            #[test]
+            fn test_stale_handle() {
+                let mut heap = $heap::new();
+                let first = heap.push(1);
+                let second = heap.push(2);
+                assert_eq!(heap.pop(), Some(1));
+                assert!(!heap.contains(first));
+                assert_eq!(heap.get(first), None);
+                assert_eq!(heap.decrease_key(first, 0), Err(StaleHandle));
+                assert_eq!(heap.remove(first), None);
+                let reused = heap.push(3);
+                assert_ne!(reused, first);
+                assert_eq!(heap.get(first), None);
+                assert_eq!(heap.remove(second), Some(2));
+                assert_eq!(heap.remove(second), None);
+                assert_eq!(heap.len(), 1);
+                assert_eq!(heap.get(reused), Some(&3));
+
+                let mut other = $heap::new();
+                let melded = other.push(0);
+                heap.meld(&mut other);
+                assert!(!other.contains(melded));
+                assert_eq!(other.decrease_key(melded, 0), Err(StaleHandle));
+                let pushed = other.push(5);
+                assert_eq!(other.get(melded), None);
+                assert_eq!(other.get(pushed), Some(&5));
+                assert_eq!(heap.get(reused), Some(&3));
+                assert_eq!(heap.decrease_key(reused, 1), Ok(()));
+                assert_eq!(heap.pop(), Some(0));
+                assert_eq!(heap.peek(), Some(&1));
+
+                heap.clear();
+                assert!(!heap.contains(reused));
+                let fresh = heap.push(4);
+                assert_eq!(heap.remove(reused), None);
+                assert_eq!(heap.remove(fresh), Some(4));
+                assert!(heap.is_empty());
+            }
+
+            #[test]
+            fn test_dijkstra() {
+                let edges: &[&[(usize, u32)]] = &[
+                    &[(1, 7), (2, 9), (5, 14)],
+                    &[(0, 7), (2, 10), (3, 15)],
+                    &[(0, 9), (1, 10), (3, 11), (5, 2)],
+                    &[(1, 15), (2, 11), (4, 6)],
+                    &[(3, 6), (5, 9)],
+                    &[(0, 14), (2, 2), (4, 9)],
+                ];
+                let mut dist = vec![u32::MAX; edges.len()];
+                let mut handles = vec![None; edges.len()];
+                let mut heap = $heap::new();
+                dist[0] = 0;
+                handles[0] = Some(heap.push((0, 0)));
+                while let Some((d, u)) = heap.pop() {
+                    handles[u] = None;
+                    for &(v, w) in edges[u] {
+                        if d + w < dist[v] {
+                            dist[v] = d + w;
+                            match handles[v] {
+                                Some(handle) => heap.decrease_key(handle, (d + w, v)).unwrap(),
+                                None => handles[v] = Some(heap.push((d + w, v))),
+                            }
+                        }
+                    }
+                }
+                assert_eq!(dist, [0, 7, 9, 20, 20, 11]);
+            }
+
+            #[test]
+            fn test_random_operations() {
+                let mut seed = 7;
+                let mut heap = $heap::new();
+                let mut reference: Vec<(u64, usize)> = Vec::new();
+                let mut handles = Vec::new();
+                for id in 0..2000 {
+                    match pseudo_random(&mut seed) % 4 {
+                        0 | 1 => {
+                            let key = pseudo_random(&mut seed) % 1000;
+                            handles.push((heap.push((key, id)), id));
+                            reference.push((key, id));
+                        }
+                        2 if !handles.is_empty() => {
+                            let at = pseudo_random(&mut seed) as usize % handles.len();
+                            let (handle, id) = handles[at];
+                            let index = reference.iter().position(|&(_, i)| i == id).unwrap();
+                            let key = reference[index].0 / 2;
+                            reference[index].0 = key;
+                            heap.decrease_key(handle, (key, id)).unwrap();
+                        }
+                        _ if !handles.is_empty() => {
+                            let at = pseudo_random(&mut seed) as usize % handles.len();
+                            let (handle, id) = handles.swap_remove(at);
+                            let index = reference.iter().position(|&(_, i)| i == id).unwrap();
+                            assert_eq!(heap.remove(handle), Some(reference.swap_remove(index)));
+                        }
+                        _ => (),
+                    }
+                    assert_eq!(heap.len(), reference.len());
+                    assert_eq!(heap.peek(), reference.iter().min());
+                    if id % 10 == 0 {
+                        if let Some(min) = heap.pop() {
+                            let index = reference.iter().position(|&e| e == min).unwrap();
+                            reference.swap_remove(index);
+                            handles.retain(|&(_, i)| i != min.1);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_drop() {
+                static mut DROPS: i32 = 0;
+
+                #[derive(PartialEq, Eq, PartialOrd, Ord)]
+                struct Elem(i32);
+
+                impl Drop for Elem {
+                    fn drop(&mut self) {
+                        unsafe {
+                            DROPS += 1;
+                        }
+                    }
+                }
+
+                let mut heap: $heap<_> = (0..10).map(Elem).collect();
+                drop(heap.pop());
+                assert_eq!(unsafe { DROPS }, 1);
+                drop(heap);
+                assert_eq!(unsafe { DROPS }, 10);
+            }
+
+            #[test]
+            fn test_drop_deep() {
+                let mut heap: $heap<_> = (0..100_000).rev().collect();
+                assert_eq!(heap.pop(), Some(0));
+                drop(heap);
+            }
+        }
+    };
+}
+
+heap_tests!(pairing_tests, PairingHeap);
+heap_tests!(fibonacci_tests, FibonacciHeap);
@@ -1 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+#[cfg(target_has_atomic = "ptr")]
+pub mod concurrent;
+pub mod dlx;
+#[cfg(feature = "std")]
+pub mod encoding;
+pub mod heap;
 pub mod linked_list;
+pub mod self_organizing_list;
+pub mod sparse_matrix;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(test)]
+mod test_util;
+pub mod timer_wheel;
@@ -0,0 +1,359 @@
+use core::{fmt::Debug, iter::FusedIterator};
+
+use super::{IntoIter, LinkedList, ListSlice};
+
+pub struct Chunks<'a, T> {
+    slice: ListSlice<'a, T>,
+    size: usize,
+}
+
+pub struct ChunksExact<'a, T> {
+    slice: ListSlice<'a, T>,
+    remainder: ListSlice<'a, T>,
+    size: usize,
+}
+
+pub struct RChunks<'a, T> {
+    slice: ListSlice<'a, T>,
+    size: usize,
+}
+
+pub struct Windows<'a, T> {
+    front: ListSlice<'a, T>,
+    back: ListSlice<'a, T>,
+    left: usize,
+}
+
+pub struct IntoChunks<T> {
+    list: LinkedList<T>,
+    size: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size > 0, "Cannot split into chunks of size zero");
+        Chunks {
+            slice: self.slice(..),
+            size,
+        }
+    }
+
+    pub fn chunks_exact(&self, size: usize) -> ChunksExact<'_, T> {
+        assert!(size > 0, "Cannot split into chunks of size zero");
+        let (slice, remainder) = self.slice(..).split_at(self.len() - self.len() % size);
+        ChunksExact {
+            slice,
+            remainder,
+            size,
+        }
+    }
+
+    pub fn rchunks(&self, size: usize) -> RChunks<'_, T> {
+        assert!(size > 0, "Cannot split into chunks of size zero");
+        RChunks {
+            slice: self.slice(..),
+            size,
+        }
+    }
+
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size > 0, "Cannot create windows of size zero");
+        if size > self.len() {
+            return Windows {
+                front: self.slice(..0),
+                back: self.slice(..0),
+                left: 0,
+            };
+        }
+        Windows {
+            front: self.slice(..size),
+            back: self.slice(self.len() - size..),
+            left: self.len() - size + 1,
+        }
+    }
+}
+
+// Chunks
+
+impl<T> Clone for Chunks<'_, T> {
+    fn clone(&self) -> Self {
+        Chunks {
+            slice: self.slice,
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Debug> Debug for Chunks<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Chunks")
+            .field("slice", &self.slice)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<T> DoubleEndedIterator for Chunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let last = match self.slice.len() % self.size {
+            0 => self.size,
+            rest => rest,
+        };
+        let (rest, chunk) = self.slice.split_at(self.slice.len() - last);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+impl<T> FusedIterator for Chunks<'_, T> {}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = ListSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.slice.split_at(self.size.min(self.slice.len()));
+        self.slice = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+// ChunksExact
+
+impl<'a, T> ChunksExact<'a, T> {
+    pub fn remainder(&self) -> ListSlice<'a, T> {
+        self.remainder
+    }
+}
+
+impl<T> Clone for ChunksExact<'_, T> {
+    fn clone(&self) -> Self {
+        ChunksExact {
+            slice: self.slice,
+            remainder: self.remainder,
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Debug> Debug for ChunksExact<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunksExact")
+            .field("slice", &self.slice)
+            .field("remainder", &self.remainder)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<T> DoubleEndedIterator for ChunksExact<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let (rest, chunk) = self.slice.split_at(self.slice.len() - self.size);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for ChunksExact<'_, T> {}
+
+impl<T> FusedIterator for ChunksExact<'_, T> {}
+
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = ListSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.slice.split_at(self.size);
+        self.slice = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len() / self.size;
+        (len, Some(len))
+    }
+}
+
+// RChunks
+
+impl<T> Clone for RChunks<'_, T> {
+    fn clone(&self) -> Self {
+        RChunks {
+            slice: self.slice,
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Debug> Debug for RChunks<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RChunks")
+            .field("slice", &self.slice)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<T> DoubleEndedIterator for RChunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let first = match self.slice.len() % self.size {
+            0 => self.size,
+            rest => rest,
+        };
+        let (chunk, rest) = self.slice.split_at(first);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for RChunks<'_, T> {}
+
+impl<T> FusedIterator for RChunks<'_, T> {}
+
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = ListSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let (rest, chunk) = self
+            .slice
+            .split_at(self.slice.len().saturating_sub(self.size));
+        self.slice = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+// Windows
+
+impl<T> Clone for Windows<'_, T> {
+    fn clone(&self) -> Self {
+        Windows {
+            front: self.front,
+            back: self.back,
+            left: self.left,
+        }
+    }
+}
+
+impl<T: Debug> Debug for Windows<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Windows")
+            .field("front", &self.front)
+            .field("left", &self.left)
+            .finish()
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        self.left -= 1;
+        let window = self.back;
+        if self.left > 0 {
+            let iter = &mut self.back.iter;
+            iter.head = iter.head.as_mut_unchecked().prev;
+            iter.tail = iter.tail.as_mut_unchecked().prev;
+            self.back.start -= 1;
+        }
+        Some(window)
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = ListSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        self.left -= 1;
+        let window = self.front;
+        // sliding both ends keeps every step O(1) regardless of the window size
+        if self.left > 0 {
+            let iter = &mut self.front.iter;
+            iter.head = iter.head.as_mut_unchecked().next;
+            iter.tail = iter.tail.as_mut_unchecked().next;
+            self.front.start += 1;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+// IntoChunks
+
+impl<T> IntoIter<T> {
+    pub fn chunks(self, size: usize) -> IntoChunks<T> {
+        assert!(size > 0, "Cannot split into chunks of size zero");
+        IntoChunks {
+            list: self.list,
+            size,
+        }
+    }
+}
+
+impl<T: Debug> Debug for IntoChunks<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IntoChunks")
+            .field("list", &self.list)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoChunks<T> {}
+
+impl<T> FusedIterator for IntoChunks<T> {}
+
+impl<T> Iterator for IntoChunks<T> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+        let rest = self.list.split_off(self.size.min(self.list.len()));
+        Some(core::mem::replace(&mut self.list, rest))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
@@ -1,70 +1,97 @@
-use std::{fmt::Debug, hash::Hash, iter::FusedIterator, marker::PhantomData, ptr::NonNull};
-
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::Debug, hash::Hash, iter::FusedIterator, marker::PhantomData, num::NonZeroUsize,
+    ptr::NonNull,
+};
+
+mod chunks;
+#[cfg(feature = "std")]
+mod parallel;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+mod snapshot;
 #[cfg(test)]
 mod tests;
 
-struct NodePtr<T> {
-    ptr: Option<NonNull<Node<T>>>,
+#[cfg(feature = "rayon")]
+pub use self::rayon::{IntoParIter, ParIter, ParIterMut};
+pub use chunks::{Chunks, ChunksExact, IntoChunks, RChunks, Windows};
+pub use slice::{ListSlice, ListSliceMut};
+pub use snapshot::ListSnapshot;
+
+// a nullable link to a heap allocated node, shared by every structure built from linked nodes
+pub(crate) struct LinkPtr<N> {
+    pub(crate) ptr: Option<NonNull<N>>,
 }
 
-impl<T> NodePtr<T> {
-    fn new(elt: T, prev: &NodePtr<T>, next: &NodePtr<T>) -> NodePtr<T> {
-        let raw = Box::into_raw(Box::new(Node {
-            value: elt,
-            next: *next,
-            prev: *prev,
-        }));
-        NodePtr {
-            ptr: Some(unsafe { NonNull::new_unchecked(raw) }),
+pub(crate) type NodePtr<T> = LinkPtr<Node<T>>;
+
+impl<N> LinkPtr<N> {
+    pub(crate) fn from_box(node: Box<N>) -> LinkPtr<N> {
+        LinkPtr {
+            ptr: Some(unsafe { NonNull::new_unchecked(Box::into_raw(node)) }),
         }
     }
 
-    fn into_box(self) -> Option<Box<Node<T>>> {
+    pub(crate) fn into_box(self) -> Option<Box<N>> {
         self.ptr
             .map(|valid_ptr| unsafe { Box::from_raw(valid_ptr.as_ptr()) })
     }
 
-    fn into_box_unchecked(self) -> Box<Node<T>> {
+    pub(crate) fn into_box_unchecked(self) -> Box<N> {
         unsafe { Box::from_raw(self.ptr.unwrap_unchecked().as_ptr()) }
     }
 
-    fn as_ref<'a>(&self) -> Option<&'a Node<T>> {
+    pub(crate) fn as_ref<'a>(&self) -> Option<&'a N> {
         self.ptr.map(|valid_ptr| unsafe { valid_ptr.as_ref() })
     }
 
-    fn as_mut<'a>(&mut self) -> Option<&'a mut Node<T>> {
+    pub(crate) fn as_mut<'a>(&mut self) -> Option<&'a mut N> {
         self.ptr
             .as_mut()
             .map(|valid_ptr| unsafe { valid_ptr.as_mut() })
     }
 
-    fn as_mut_unchecked<'a>(&self) -> &'a mut Node<T> {
+    pub(crate) fn as_mut_unchecked<'a>(&self) -> &'a mut N {
         unsafe { self.ptr.unwrap_unchecked().as_mut() }
     }
 }
 
-impl<T> Clone for NodePtr<T> {
+impl<T> NodePtr<T> {
+    pub(crate) fn new(elt: T, prev: &NodePtr<T>, next: &NodePtr<T>) -> NodePtr<T> {
+        LinkPtr::from_box(Box::new(Node {
+            value: elt,
+            next: *next,
+            prev: *prev,
+        }))
+    }
+}
+
+impl<N> Clone for LinkPtr<N> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<T> Copy for NodePtr<T> {}
+impl<N> Copy for LinkPtr<N> {}
 
-impl<T> Default for NodePtr<T> {
+impl<N> Default for LinkPtr<N> {
     fn default() -> Self {
-        NodePtr { ptr: None }
+        LinkPtr { ptr: None }
     }
 }
 
-unsafe impl<T: Sync> Send for NodePtr<T> {}
+unsafe impl<N: Send> Send for LinkPtr<N> {}
 
-unsafe impl<T: Sync> Sync for NodePtr<T> {}
+unsafe impl<N: Sync> Sync for LinkPtr<N> {}
 
-struct Node<T> {
-    value: T,
-    next: NodePtr<T>,
-    prev: NodePtr<T>,
+pub(crate) struct Node<T> {
+    pub(crate) value: T,
+    pub(crate) next: NodePtr<T>,
+    pub(crate) prev: NodePtr<T>,
 }
 
 pub struct Iter<'a, T> {
@@ -93,21 +120,94 @@ pub struct Cursor<'a, T> {
 
 pub struct CursorMut<'a, T> {
     next_index: usize,
-    current: NodePtr<T>,
+    pub(crate) current: NodePtr<T>,
     list: &'a mut LinkedList<T>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Position {
+    Before,
+    After,
+}
+
+pub struct DetachedNode<T> {
+    node: Box<Node<T>>,
+}
+
 pub struct DrainFilter<'a, T, F: FnMut(&mut T) -> bool> {
     current: NodePtr<T>,
+    back: NodePtr<T>,
     pred: F,
     list: &'a mut LinkedList<T>,
     max_left: usize,
 }
 
 pub struct LinkedList<T> {
+    pub(crate) head: NodePtr<T>,
+    pub(crate) tail: NodePtr<T>,
+    pub(crate) len: usize,
+}
+
+// returns the last node of the left part and the first node of the right part
+fn _split_point<T>(
     head: NodePtr<T>,
     tail: NodePtr<T>,
     len: usize,
+    at: usize,
+) -> (NodePtr<T>, NodePtr<T>) {
+    if at == 0 {
+        return (Default::default(), head);
+    } else if at == len {
+        return (tail, Default::default());
+    }
+    let mut node = if at <= len / 2 {
+        let mut node = head;
+        for _ in 0..at {
+            node = node.as_mut_unchecked().next;
+        }
+        node
+    } else {
+        let mut node = tail;
+        for _ in at..len - 1 {
+            node = node.as_mut_unchecked().prev;
+        }
+        node
+    };
+    (node.as_mut().unwrap().prev, node)
+}
+
+fn _merge<T, F: FnMut(&T, &T) -> core::cmp::Ordering>(
+    mut left: LinkedList<T>,
+    mut right: LinkedList<T>,
+    compare: &mut F,
+) -> LinkedList<T> {
+    let mut merged = LinkedList::new();
+    while let (Some(l), Some(r)) = (left.front(), right.front()) {
+        let from = if compare(r, l) == core::cmp::Ordering::Less {
+            &mut right
+        } else {
+            &mut left
+        };
+        let mut rest = from.split_off(1);
+        merged.append(from);
+        core::mem::swap(from, &mut rest);
+    }
+    merged.append(&mut left);
+    merged.append(&mut right);
+    merged
+}
+
+fn _merge_sort<T, F: FnMut(&T, &T) -> core::cmp::Ordering>(
+    mut list: LinkedList<T>,
+    compare: &mut F,
+) -> LinkedList<T> {
+    if list.len() < 2 {
+        return list;
+    }
+    let right = list.split_off(list.len() / 2);
+    let left = _merge_sort(list, compare);
+    let right = _merge_sort(right, compare);
+    _merge(left, right, compare)
 }
 
 impl<T> LinkedList<T> {
@@ -123,17 +223,17 @@ impl<T> LinkedList<T> {
         if other.is_empty() {
             return;
         } else if self.is_empty() {
-            std::mem::swap(self, other);
+            core::mem::swap(self, other);
             return;
         }
-        self.len += std::mem::take(&mut other.len);
-        let other_head = std::mem::take(&mut other.head);
+        self.len += core::mem::take(&mut other.len);
+        let other_head = core::mem::take(&mut other.head);
         self.tail.as_mut_unchecked().next = other_head;
         other_head.as_mut_unchecked().prev =
-            std::mem::replace(&mut self.tail, std::mem::take(&mut other.tail));
+            core::mem::replace(&mut self.tail, core::mem::take(&mut other.tail));
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             head: self.head,
             tail: self.tail,
@@ -142,7 +242,7 @@ impl<T> LinkedList<T> {
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             head: self.head,
             tail: self.tail,
@@ -151,7 +251,7 @@ impl<T> LinkedList<T> {
         }
     }
 
-    pub fn cursor_front(&self) -> Cursor<T> {
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
         Cursor {
             next_index: 1,
             current: self.head,
@@ -159,7 +259,7 @@ impl<T> LinkedList<T> {
         }
     }
 
-    pub fn cursor_front_mut(&mut self) -> CursorMut<T> {
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
         CursorMut {
             next_index: 1,
             current: self.head,
@@ -167,7 +267,7 @@ impl<T> LinkedList<T> {
         }
     }
 
-    pub fn cursor_back(&self) -> Cursor<T> {
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
         Cursor {
             next_index: self.len(),
             current: self.tail,
@@ -175,7 +275,7 @@ impl<T> LinkedList<T> {
         }
     }
 
-    pub fn cursor_back_mut(&mut self) -> CursorMut<T> {
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
         CursorMut {
             next_index: self.len(),
             current: self.tail,
@@ -202,6 +302,52 @@ impl<T> LinkedList<T> {
         self.iter().any(|elt| elt == x)
     }
 
+    pub fn find_subsequence(&self, pattern: &[T]) -> Option<Cursor<'_, T>>
+    where
+        T: PartialEq<T>,
+    {
+        if pattern.is_empty() {
+            return Some(self.cursor_front());
+        }
+        // Knuth-Morris-Pratt: fallback[i] is the length of the longest proper border of pattern[..=i]
+        let mut fallback = Vec::with_capacity(pattern.len());
+        let mut border = 0;
+        for (i, elt) in pattern.iter().enumerate() {
+            while border > 0 && pattern[border] != *elt {
+                border = fallback[border - 1];
+            }
+            if i > 0 && pattern[border] == *elt {
+                border += 1;
+            }
+            fallback.push(border);
+        }
+        let mut matched = 0;
+        for (i, elt) in self.iter().enumerate() {
+            while matched > 0 && pattern[matched] != *elt {
+                matched = fallback[matched - 1];
+            }
+            if pattern[matched] == *elt {
+                matched += 1;
+            }
+            if matched == pattern.len() {
+                let start = i + 1 - matched;
+                return Some(Cursor {
+                    next_index: start + 1,
+                    current: self._pointer_at(start),
+                    list: self,
+                });
+            }
+        }
+        None
+    }
+
+    pub fn contains_subsequence(&self, pattern: &[T]) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.find_subsequence(pattern).is_some()
+    }
+
     pub fn front(&self) -> Option<&T> {
         self.head.as_ref().map(|node| &node.value)
     }
@@ -220,7 +366,7 @@ impl<T> LinkedList<T> {
 
     pub fn push_front(&mut self, elt: T) {
         let new_head = NodePtr::new(elt, &Default::default(), &self.head);
-        let mut old_head = std::mem::replace(&mut self.head, new_head);
+        let mut old_head = core::mem::replace(&mut self.head, new_head);
         if let Some(old_node) = old_head.as_mut() {
             old_node.prev = new_head;
         } else {
@@ -250,7 +396,7 @@ impl<T> LinkedList<T> {
 
     pub fn push_back(&mut self, elt: T) {
         let new_tail = NodePtr::new(elt, &self.tail, &Default::default());
-        let mut old_tail = std::mem::replace(&mut self.tail, new_tail);
+        let mut old_tail = core::mem::replace(&mut self.tail, new_tail);
         if let Some(old_node) = old_tail.as_mut() {
             old_node.next = new_tail;
         } else {
@@ -288,28 +434,85 @@ impl<T> LinkedList<T> {
 
     fn _pointer_at_from_tail(&self, at: usize) -> NodePtr<T> {
         let mut node_ptr = self.tail;
-        for _ in 0..self.len() - at {
+        for _ in 0..self.len() - 1 - at {
             node_ptr = node_ptr.as_mut_unchecked().prev;
         }
         node_ptr
     }
 
-    fn _cursor_at_mut(&mut self, at: usize) -> CursorMut<T> {
-        let node_ptr = if at <= self.len() / 2 {
+    fn _pointer_at(&self, at: usize) -> NodePtr<T> {
+        if at <= self.len() / 2 {
             self._pointer_at_from_head(at)
         } else {
             self._pointer_at_from_tail(at)
-        };
+        }
+    }
+
+    fn _seek_from(&self, current: NodePtr<T>, index: Option<usize>, at: usize) -> NodePtr<T> {
+        assert!(
+            at < self.len(),
+            "Cannot seek to an index outside of the list bounds"
+        );
+        let from_ends = at.min(self.len() - 1 - at);
+        match index {
+            Some(index) if index.abs_diff(at) < from_ends => {
+                let mut node_ptr = current;
+                for _ in at..index {
+                    node_ptr = node_ptr.as_mut_unchecked().prev;
+                }
+                for _ in index..at {
+                    node_ptr = node_ptr.as_mut_unchecked().next;
+                }
+                node_ptr
+            }
+            _ => self._pointer_at(at),
+        }
+    }
+
+    // positions form a ring of the elements followed by the ghost
+    fn _position_by(&self, index: Option<usize>, offset: isize) -> Option<usize> {
+        let positions = self.len() as isize + 1;
+        let position = index.unwrap_or(self.len()) as isize;
+        let target = (position + offset % positions).rem_euclid(positions) as usize;
+        (target < self.len()).then_some(target)
+    }
+
+    fn _cursor_at_mut(&mut self, at: usize) -> CursorMut<'_, T> {
         CursorMut {
             next_index: at + 1,
-            current: node_ptr,
+            current: self._pointer_at(at),
             list: self,
         }
     }
 
+    pub(crate) fn _unlink_as_list(&mut self, node_ptr: NodePtr<T>) -> LinkedList<T> {
+        self._unlink_range(node_ptr, node_ptr, 1)
+    }
+
+    fn _unlink_range(&mut self, first: NodePtr<T>, last: NodePtr<T>, len: usize) -> LinkedList<T> {
+        let mut before = first.as_mut_unchecked().prev;
+        let mut after = last.as_mut_unchecked().next;
+        self.len -= len;
+        match before.as_mut() {
+            Some(node) => node.next = after,
+            None => self.head = after,
+        }
+        match after.as_mut() {
+            Some(node) => node.prev = before,
+            None => self.tail = before,
+        }
+        first.as_mut_unchecked().prev = Default::default();
+        last.as_mut_unchecked().next = Default::default();
+        LinkedList {
+            head: first,
+            tail: last,
+            len,
+        }
+    }
+
     pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
         if at == 0 {
-            std::mem::take(self)
+            core::mem::take(self)
         } else if at <= self.len() {
             self._cursor_at_mut(at - 1).split_after()
         } else {
@@ -325,24 +528,41 @@ impl<T> LinkedList<T> {
         unsafe { self._cursor_at_mut(at).remove_current().unwrap_unchecked() }
     }
 
-    pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> DrainFilter<T, F> {
+    pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> DrainFilter<'_, T, F> {
         DrainFilter {
             current: self.head,
+            back: self.tail,
             pred: filter,
             max_left: self.len(),
             list: self,
         }
     }
+
+    pub fn extract_if<R, F>(&mut self, range: R, filter: F) -> DrainFilter<'_, T, F>
+    where
+        R: core::ops::RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        let (start, end) = self._range(range);
+        let (current, back) = self._range_pointers(start, end);
+        DrainFilter {
+            current,
+            back,
+            pred: filter,
+            max_left: end - start,
+            list: self,
+        }
+    }
 }
 
 impl<T: Clone> Clone for LinkedList<T> {
     fn clone(&self) -> Self {
-        self.iter().map(|elt| elt.clone()).collect()
+        self.iter().cloned().collect()
     }
 }
 
 impl<T: Debug> Debug for LinkedList<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self).finish()
     }
 }
@@ -368,7 +588,7 @@ impl<T> Drop for LinkedList<T> {
             node_ptr = boxed.next;
             let guard = DropGuard(node_ptr);
             drop(boxed); // can panic
-            std::mem::forget(guard);
+            core::mem::forget(guard);
         }
     }
 }
@@ -400,7 +620,7 @@ impl<T> FromIterator<T> for LinkedList<T> {
 }
 
 impl<T: Hash> Hash for LinkedList<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         state.write_usize(self.len()); // write_length_prefix
         for elt in self {
             elt.hash(state);
@@ -446,7 +666,7 @@ impl<T> IntoIterator for LinkedList<T> {
 }
 
 impl<T: Ord> Ord for LinkedList<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.iter().cmp(other)
     }
 }
@@ -458,7 +678,7 @@ impl<T: PartialEq<T>> PartialEq for LinkedList<T> {
 }
 
 impl<T: PartialOrd<T>> PartialOrd for LinkedList<T> {
-    fn partial_cmp(&self, other: &LinkedList<T>) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &LinkedList<T>) -> Option<core::cmp::Ordering> {
         self.iter().partial_cmp(other)
     }
 }
@@ -467,6 +687,22 @@ impl<T: Eq> Eq for LinkedList<T> {}
 
 // Iter
 
+impl<T> Iter<'_, T> {
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.left);
+        self.head = _split_point(self.head, self.tail, self.left, step).1;
+        self.left -= step;
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.left);
+        self.tail = _split_point(self.head, self.tail, self.left, self.left - step).0;
+        self.left -= step;
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+}
+
 impl<T> Clone for Iter<'_, T> {
     fn clone(&self) -> Self {
         *self
@@ -476,9 +712,9 @@ impl<T> Clone for Iter<'_, T> {
 impl<T> Copy for Iter<'_, T> {}
 
 impl<T: Debug> Debug for Iter<'_, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Iter")
-            .field(&*std::mem::ManuallyDrop::new(LinkedList {
+            .field(&*core::mem::ManuallyDrop::new(LinkedList {
                 head: self.head,
                 tail: self.tail,
                 len: self.left,
@@ -498,16 +734,44 @@ impl<T> DoubleEndedIterator for Iter<'_, T> {
         self.left -= 1;
         Some(&node.value)
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.left {
+            self.left = 0;
+            return None;
+        }
+        let node = _split_point(self.head, self.tail, self.left, self.left - 1 - n)
+            .1
+            .as_mut_unchecked();
+        self.tail = node.prev;
+        self.left -= n + 1;
+        Some(&node.value)
+    }
+
+    fn rfold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut node_ptr = self.tail;
+        for _ in 0..self.left {
+            let node = node_ptr.as_mut_unchecked();
+            node_ptr = node.prev;
+            acc = f(acc, &node.value);
+        }
+        acc
+    }
 }
 
 impl<T> ExactSizeIterator for Iter<'_, T> {
     fn len(&self) -> usize {
-        return self.left;
+        self.left
     }
 }
 
 impl<T> FusedIterator for Iter<'_, T> {}
 
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -525,6 +789,34 @@ impl<'a, T> Iterator for Iter<'a, T> {
         (self.left, Some(self.left))
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.left {
+            self.left = 0;
+            return None;
+        }
+        let node = _split_point(self.head, self.tail, self.left, n)
+            .1
+            .as_mut_unchecked();
+        self.head = node.next;
+        self.left -= n + 1;
+        Some(&node.value)
+    }
+
+    fn count(self) -> usize {
+        self.left
+    }
+
+    fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut node_ptr = self.head;
+        for _ in 0..self.left {
+            let node = node_ptr.as_mut_unchecked();
+            node_ptr = node.next;
+            acc = f(acc, &node.value);
+        }
+        acc
+    }
+
     fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
@@ -535,10 +827,26 @@ impl<'a, T> Iterator for Iter<'a, T> {
 
 // IterMut
 
+impl<T> IterMut<'_, T> {
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.left);
+        self.head = _split_point(self.head, self.tail, self.left, step).1;
+        self.left -= step;
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.left);
+        self.tail = _split_point(self.head, self.tail, self.left, self.left - step).0;
+        self.left -= step;
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+}
+
 impl<T: Debug> Debug for IterMut<'_, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Iter")
-            .field(&*std::mem::ManuallyDrop::new(LinkedList {
+            .field(&*core::mem::ManuallyDrop::new(LinkedList {
                 head: self.head,
                 tail: self.tail,
                 len: self.left,
@@ -558,16 +866,44 @@ impl<T> DoubleEndedIterator for IterMut<'_, T> {
         self.left -= 1;
         Some(&mut node.value)
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.left {
+            self.left = 0;
+            return None;
+        }
+        let node = _split_point(self.head, self.tail, self.left, self.left - 1 - n)
+            .1
+            .as_mut_unchecked();
+        self.tail = node.prev;
+        self.left -= n + 1;
+        Some(&mut node.value)
+    }
+
+    fn rfold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut node_ptr = self.tail;
+        for _ in 0..self.left {
+            let node = node_ptr.as_mut_unchecked();
+            node_ptr = node.prev;
+            acc = f(acc, &mut node.value);
+        }
+        acc
+    }
 }
 
 impl<T> ExactSizeIterator for IterMut<'_, T> {
     fn len(&self) -> usize {
-        return self.left;
+        self.left
     }
 }
 
 impl<T> FusedIterator for IterMut<'_, T> {}
 
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
 impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
@@ -585,6 +921,34 @@ impl<'a, T> Iterator for IterMut<'a, T> {
         (self.left, Some(self.left))
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.left {
+            self.left = 0;
+            return None;
+        }
+        let node = _split_point(self.head, self.tail, self.left, n)
+            .1
+            .as_mut_unchecked();
+        self.head = node.next;
+        self.left -= n + 1;
+        Some(&mut node.value)
+    }
+
+    fn count(self) -> usize {
+        self.left
+    }
+
+    fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut node_ptr = self.head;
+        for _ in 0..self.left {
+            let node = node_ptr.as_mut_unchecked();
+            node_ptr = node.next;
+            acc = f(acc, &mut node.value);
+        }
+        acc
+    }
+
     fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
@@ -595,8 +959,39 @@ impl<'a, T> Iterator for IterMut<'a, T> {
 
 // IntoIter
 
+impl<T> IntoIter<T> {
+    pub fn as_list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list
+    }
+
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.list.len());
+        let rest = self.list.split_off(step);
+        drop(core::mem::replace(&mut self.list, rest));
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.list.len());
+        drop(self.list.split_off(self.list.len() - step));
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+}
+
+impl<T: Clone> Clone for IntoIter<T> {
+    fn clone(&self) -> Self {
+        IntoIter {
+            list: self.list.clone(),
+        }
+    }
+}
+
 impl<T: Debug> Debug for IntoIter<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IntoIter").field(&self.list).finish()
     }
 }
@@ -605,6 +1000,19 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.list.pop_back()
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.advance_back_by(n).ok()?;
+        self.next_back()
+    }
+
+    fn rfold<B, F: FnMut(B, Self::Item) -> B>(mut self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        while let Some(elt) = self.list.pop_back() {
+            acc = f(acc, elt);
+        }
+        acc
+    }
 }
 
 impl<T> ExactSizeIterator for IntoIter<T> {
@@ -626,6 +1034,23 @@ impl<T> Iterator for IntoIter<T> {
         (self.list.len(), Some(self.list.len()))
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.list.len()
+    }
+
+    fn fold<B, F: FnMut(B, Self::Item) -> B>(mut self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        while let Some(elt) = self.list.pop_front() {
+            acc = f(acc, elt);
+        }
+        acc
+    }
+
     fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
@@ -634,6 +1059,61 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
+// DetachedNode
+
+impl<T> DetachedNode<T> {
+    pub fn new(value: T) -> DetachedNode<T> {
+        DetachedNode {
+            node: Box::new(Node {
+                value,
+                next: Default::default(),
+                prev: Default::default(),
+            }),
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.node.value
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.node.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.node.value
+    }
+
+    fn _into_list(self) -> LinkedList<T> {
+        let node = NodePtr {
+            ptr: Some(NonNull::from(Box::leak(self.node))),
+        };
+        LinkedList {
+            head: node,
+            tail: node,
+            len: 1,
+        }
+    }
+}
+
+impl<T: Clone> Clone for DetachedNode<T> {
+    fn clone(&self) -> Self {
+        DetachedNode::new(self.get().clone())
+    }
+}
+
+impl<T: Debug> Debug for DetachedNode<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("DetachedNode").field(self.get()).finish()
+    }
+}
+
+impl<T> From<DetachedNode<T>> for LinkedList<T> {
+    fn from(node: DetachedNode<T>) -> Self {
+        node._into_list()
+    }
+}
+
 // Cursor
 
 impl<'a, T> Cursor<'a, T> {
@@ -642,6 +1122,104 @@ impl<'a, T> Cursor<'a, T> {
         Some(self.next_index - 1)
     }
 
+    pub fn is_ghost(&self) -> bool {
+        self.current.ptr.is_none()
+    }
+
+    pub fn seek(&mut self, index: usize) {
+        self.current = self.list._seek_from(self.current, self.index(), index);
+        self.next_index = index + 1;
+    }
+
+    pub fn move_by(&mut self, offset: isize) {
+        match self.list._position_by(self.index(), offset) {
+            Some(index) => self.seek(index),
+            None => self.move_to_ghost(),
+        }
+    }
+
+    pub fn move_to_front(&mut self) {
+        self.current = self.list.head;
+        self.next_index = 1;
+    }
+
+    pub fn move_to_back(&mut self) {
+        self.current = self.list.tail;
+        self.next_index = self.list.len();
+    }
+
+    pub fn move_to_ghost(&mut self) {
+        self.current = Default::default();
+        self.next_index = 0;
+    }
+
+    pub fn iter_forward(&self) -> Iter<'a, T> {
+        Iter {
+            head: self
+                .current
+                .as_ref()
+                .map_or(self.list.head, |node| node.next),
+            tail: self.list.tail,
+            left: self
+                .index()
+                .map_or(self.list.len(), |index| self.list.len() - index - 1),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn iter_backward(&self) -> core::iter::Rev<Iter<'a, T>> {
+        Iter {
+            head: self.list.head,
+            tail: self
+                .current
+                .as_ref()
+                .map_or(self.list.tail, |node| node.prev),
+            left: self.index().unwrap_or(self.list.len()),
+            phantom: PhantomData,
+        }
+        .rev()
+    }
+
+    // the ghost counts as the position after the back of the list
+    pub fn iter_to(&self, end: &Cursor<'a, T>) -> Iter<'a, T> {
+        assert!(
+            core::ptr::eq(self.list, end.list),
+            "Cursors must point into the same list"
+        );
+        let start_index = self.index().unwrap_or(self.list.len());
+        let end_index = end.index().unwrap_or(self.list.len());
+        assert!(start_index <= end_index, "Cursors are out of order");
+        Iter {
+            head: self.current,
+            tail: end
+                .current
+                .as_ref()
+                .map_or(self.list.tail, |node| node.prev),
+            left: end_index - start_index,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn find_next<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&'a T> {
+        loop {
+            self.move_next();
+            let value = &self.current.as_ref()?.value;
+            if pred(value) {
+                return Some(value);
+            }
+        }
+    }
+
+    pub fn find_prev<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&'a T> {
+        loop {
+            self.move_prev();
+            let value = &self.current.as_ref()?.value;
+            if pred(value) {
+                return Some(value);
+            }
+        }
+    }
+
     pub fn move_next(&mut self) {
         if let Some(node) = self.current.as_ref() {
             self.current = node.next;
@@ -700,7 +1278,7 @@ impl<T> Clone for Cursor<'_, T> {
 impl<T> Copy for Cursor<'_, T> {}
 
 impl<T: Debug> Debug for Cursor<'_, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Cursor")
             .field(&self.list)
             .field(&self.index())
@@ -708,6 +1286,10 @@ impl<T: Debug> Debug for Cursor<'_, T> {
     }
 }
 
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+
 // CursorMut
 
 impl<'a, T> CursorMut<'a, T> {
@@ -716,6 +1298,69 @@ impl<'a, T> CursorMut<'a, T> {
         Some(self.next_index - 1)
     }
 
+    pub fn is_ghost(&self) -> bool {
+        self.current.ptr.is_none()
+    }
+
+    pub fn seek(&mut self, index: usize) {
+        self.current = self.list._seek_from(self.current, self.index(), index);
+        self.next_index = index + 1;
+    }
+
+    pub fn move_by(&mut self, offset: isize) {
+        match self.list._position_by(self.index(), offset) {
+            Some(index) => self.seek(index),
+            None => self.move_to_ghost(),
+        }
+    }
+
+    pub fn move_to_front(&mut self) {
+        self.current = self.list.head;
+        self.next_index = 1;
+    }
+
+    pub fn move_to_back(&mut self) {
+        self.current = self.list.tail;
+        self.next_index = self.list.len();
+    }
+
+    pub fn move_to_ghost(&mut self) {
+        self.current = Default::default();
+        self.next_index = 0;
+    }
+
+    pub fn iter_mut_forward(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self
+                .current
+                .as_ref()
+                .map_or(self.list.head, |node| node.next),
+            tail: self.list.tail,
+            left: self
+                .index()
+                .map_or(self.list.len(), |index| self.list.len() - index - 1),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn find_next<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&mut T> {
+        loop {
+            self.move_next();
+            if pred(&self.current.as_ref()?.value) {
+                return self.current();
+            }
+        }
+    }
+
+    pub fn find_prev<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&mut T> {
+        loop {
+            self.move_prev();
+            if pred(&self.current.as_ref()?.value) {
+                return self.current();
+            }
+        }
+    }
+
     pub fn move_next(&mut self) {
         if let Some(node) = self.current.as_ref() {
             self.current = node.next;
@@ -756,7 +1401,7 @@ impl<'a, T> CursorMut<'a, T> {
             .map(|node| &mut node.value)
     }
 
-    pub fn as_cursor(&self) -> Cursor<T> {
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
         Cursor {
             next_index: self.next_index,
             current: self.current,
@@ -788,6 +1433,7 @@ impl<'a, T> CursorMut<'a, T> {
             } else {
                 self.list.head = inserted;
             }
+            node_after.prev = inserted;
             self.next_index += 1;
         } else {
             self.list.push_back(item);
@@ -810,28 +1456,135 @@ impl<'a, T> CursorMut<'a, T> {
     }
 
     pub fn remove_current_as_list(&mut self) -> Option<LinkedList<T>> {
-        let node = self.current.as_mut()?;
         let node_ptr = self.current;
-        self.list.len -= 1;
-        if let Some(before) = node.prev.as_mut() {
-            before.next = node.next;
+        self.current = node_ptr.as_ref()?.next;
+        Some(self.list._unlink_as_list(node_ptr))
+    }
+
+    pub fn detach_current(&mut self) -> Option<DetachedNode<T>> {
+        let node_ptr = self.current;
+        self.current = node_ptr.as_ref()?.next;
+        core::mem::forget(self.list._unlink_as_list(node_ptr));
+        Some(DetachedNode {
+            node: node_ptr.into_box_unchecked(),
+        })
+    }
+
+    pub fn attach_after(&mut self, node: DetachedNode<T>) {
+        self.splice_after(node._into_list());
+    }
+
+    pub fn attach_before(&mut self, node: DetachedNode<T>) {
+        self.splice_before(node._into_list());
+    }
+
+    pub fn remove_next_n(&mut self, n: usize) -> LinkedList<T> {
+        let first = self
+            .current
+            .as_ref()
+            .map_or(self.list.head, |node| node.next);
+        if n == 0 || first.ptr.is_none() {
+            return LinkedList::new();
         }
-        if let Some(after) = node.next.as_mut() {
-            after.prev = node.prev;
+        let (mut last, mut len) = (first, 1);
+        while len < n {
+            let next = last.as_mut_unchecked().next;
+            if next.ptr.is_none() {
+                break;
+            }
+            (last, len) = (next, len + 1);
+        }
+        self.list._unlink_range(first, last, len)
+    }
+
+    pub fn remove_until<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> LinkedList<T> {
+        let first = self
+            .current
+            .as_ref()
+            .map_or(self.list.head, |node| node.next);
+        let (mut last, mut len) = (NodePtr::default(), 0);
+        let mut next = first;
+        while let Some(node) = next.as_ref() {
+            if pred(&node.value) {
+                break;
+            }
+            (last, next, len) = (next, node.next, len + 1);
+        }
+        if len == 0 {
+            return LinkedList::new();
+        }
+        self.list._unlink_range(first, last, len)
+    }
+
+    pub fn insert_iter_after<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.splice_after(iter.into_iter().collect());
+    }
+
+    pub fn insert_iter_before<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.splice_before(iter.into_iter().collect());
+    }
+
+    pub fn replace_current(&mut self, value: T) -> T {
+        let current = self.current().expect("Cannot replace the ghost element");
+        core::mem::replace(current, value)
+    }
+
+    pub fn move_current_to_front(&mut self) {
+        if self.current.ptr.is_none() {
+            return;
+        }
+        let mut rest = self.list._unlink_as_list(self.current);
+        core::mem::swap(self.list, &mut rest);
+        self.list.append(&mut rest);
+        self.next_index = 1;
+    }
+
+    pub fn move_current_to_back(&mut self) {
+        if self.current.ptr.is_none() {
+            return;
         }
+        let mut node = self.list._unlink_as_list(self.current);
+        self.list.append(&mut node);
+        self.next_index = self.list.len();
+    }
+
+    pub fn swap_with_next(&mut self) {
+        let next = match self.current.as_ref() {
+            Some(node) if node.next.ptr.is_some() => node.next,
+            _ => return,
+        };
+        let node = self.list._unlink_as_list(next);
+        self.splice_before(node);
+    }
+
+    pub fn swap_with_prev(&mut self) {
+        let prev = match self.current.as_ref() {
+            Some(node) if node.prev.ptr.is_some() => node.prev,
+            _ => return,
+        };
+        let node = self.list._unlink_as_list(prev);
+        self.next_index -= 1;
+        self.splice_after(node);
+    }
+
+    pub fn transfer_current(&mut self, other: &mut CursorMut<'_, T>, position: Position) {
+        let node_ptr = self.current;
+        let Some(node) = node_ptr.as_ref() else {
+            return;
+        };
         self.current = node.next;
-        Some(LinkedList {
-            head: node_ptr,
-            tail: node_ptr,
-            len: 1,
-        })
+        let node = self.list._unlink_as_list(node_ptr);
+        match position {
+            Position::Before => other.splice_before(node),
+            Position::After => other.splice_after(node),
+        }
     }
 
     pub fn splice_after(&mut self, list: LinkedList<T>) {
         if list.is_empty() {
             return;
         } else if self.list.is_empty() {
-            std::mem::forget(std::mem::replace(self.list, list));
+            core::mem::forget(core::mem::replace(self.list, list));
             return;
         } else if let Some(before) = self.current.as_mut() {
             if let Some(after) = before.next.as_mut() {
@@ -844,17 +1597,17 @@ impl<'a, T> CursorMut<'a, T> {
             list.head.as_mut_unchecked().prev = self.current;
         } else {
             self.list.head.as_mut_unchecked().prev = list.tail;
-            list.tail.as_mut_unchecked().next = std::mem::replace(&mut self.list.head, list.head);
+            list.tail.as_mut_unchecked().next = core::mem::replace(&mut self.list.head, list.head);
         }
         self.list.len += list.len();
-        std::mem::forget(list);
+        core::mem::forget(list);
     }
 
     pub fn splice_before(&mut self, list: LinkedList<T>) {
         if list.is_empty() {
             return;
         } else if self.list.is_empty() {
-            std::mem::forget(std::mem::replace(self.list, list));
+            core::mem::forget(core::mem::replace(self.list, list));
             return;
         } else if let Some(after) = self.current.as_mut() {
             if let Some(before) = after.prev.as_mut() {
@@ -868,18 +1621,18 @@ impl<'a, T> CursorMut<'a, T> {
             self.next_index += list.len();
         } else {
             self.list.tail.as_mut_unchecked().next = list.head;
-            list.head.as_mut_unchecked().prev = std::mem::replace(&mut self.list.tail, list.tail);
+            list.head.as_mut_unchecked().prev = core::mem::replace(&mut self.list.tail, list.tail);
         }
         self.list.len += list.len();
-        std::mem::forget(list);
+        core::mem::forget(list);
     }
 
     pub fn split_after(&mut self) -> LinkedList<T> {
         if let Some(node) = self.current.as_mut() {
             if let Some(next) = node.next.as_mut() {
                 next.prev = Default::default();
-                let head = std::mem::take(&mut node.next);
-                let tail = std::mem::replace(&mut self.list.tail, self.current);
+                let head = core::mem::take(&mut node.next);
+                let tail = core::mem::replace(&mut self.list.tail, self.current);
                 let len = self.list.len() - self.next_index;
                 self.list.len = self.next_index;
                 LinkedList { head, tail, len }
@@ -887,7 +1640,7 @@ impl<'a, T> CursorMut<'a, T> {
                 Default::default()
             }
         } else {
-            std::mem::take(&mut self.list)
+            core::mem::take(self.list)
         }
     }
 
@@ -895,8 +1648,8 @@ impl<'a, T> CursorMut<'a, T> {
         if let Some(node) = self.current.as_mut() {
             if let Some(prev) = node.prev.as_mut() {
                 prev.next = Default::default();
-                let tail = std::mem::take(&mut node.prev);
-                let head = std::mem::replace(&mut self.list.head, self.current);
+                let tail = core::mem::take(&mut node.prev);
+                let head = core::mem::replace(&mut self.list.head, self.current);
                 let len = self.next_index - 1;
                 self.list.len -= len;
                 self.next_index = 1;
@@ -905,7 +1658,7 @@ impl<'a, T> CursorMut<'a, T> {
                 Default::default()
             }
         } else {
-            std::mem::take(&mut self.list)
+            core::mem::take(self.list)
         }
     }
 
@@ -960,7 +1713,7 @@ impl<'a, T> CursorMut<'a, T> {
 }
 
 impl<T: Debug> Debug for CursorMut<'_, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CursorMut")
             .field(&self.list)
             .field(&self.index())
@@ -969,7 +1722,7 @@ impl<T: Debug> Debug for CursorMut<'_, T> {
 }
 
 impl<T: Debug, F: FnMut(&mut T) -> bool> Debug for DrainFilter<'_, T, F> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DrainFilter").field(&self.list).finish()
     }
 }
@@ -985,33 +1738,60 @@ impl<T, F: FnMut(&mut T) -> bool> Drop for DrainFilter<'_, T, F> {
         while let Some(elt) = self.next() {
             let guard = DropGuard(self);
             drop(elt); // can panic
-            std::mem::forget(guard);
+            core::mem::forget(guard);
         }
     }
 }
 
+impl<T, F: FnMut(&mut T) -> bool> DrainFilter<'_, T, F> {
+    pub fn keep_rest(mut self) {
+        self.max_left = 0;
+    }
+
+    fn _extract(&mut self, node_ptr: NodePtr<T>) -> T {
+        let mut boxed = node_ptr.into_box_unchecked();
+        self.list.len -= 1;
+        match boxed.prev.as_mut() {
+            Some(before) => before.next = boxed.next,
+            None => self.list.head = boxed.next,
+        }
+        match boxed.next.as_mut() {
+            Some(after) => after.prev = boxed.prev,
+            None => self.list.tail = boxed.prev,
+        }
+        boxed.value
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> DoubleEndedIterator for DrainFilter<'_, T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // only the `max_left` nodes between both ends are unvisited, so
+        // the ends never walk past each other
+        while self.max_left > 0 {
+            let node_ptr = self.back;
+            let node = node_ptr.as_mut_unchecked();
+            self.back = node.prev;
+            self.max_left -= 1;
+            if (self.pred)(&mut node.value) {
+                return Some(self._extract(node_ptr));
+            }
+        }
+        None
+    }
+}
+
 impl<T, F: FnMut(&mut T) -> bool> Iterator for DrainFilter<'_, T, F> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(node) = self.current.as_mut() {
-            self.max_left -= 1;
-            if !(self.pred)(&mut node.value) {
-                self.current = node.next;
-                continue;
-            }
-            let mut boxed = self.current.into_box()?;
+        while self.max_left > 0 {
+            let node_ptr = self.current;
+            let node = node_ptr.as_mut_unchecked();
             self.current = node.next;
-            self.list.len -= 1;
-            match boxed.prev.as_mut() {
-                Some(before) => before.next = boxed.next,
-                None => self.list.head = boxed.next,
-            }
-            match boxed.next.as_mut() {
-                Some(after) => after.prev = boxed.prev,
-                None => self.list.tail = boxed.prev,
+            self.max_left -= 1;
+            if (self.pred)(&mut node.value) {
+                return Some(self._extract(node_ptr));
             }
-            return Some(boxed.value);
         }
         None
     }
@@ -0,0 +1,161 @@
+use std::{cmp::Ordering, marker::PhantomData, thread};
+
+use super::{_merge, _merge_sort, Iter, LinkedList};
+
+fn _segment_len(len: usize, threads: usize) -> usize {
+    assert!(threads > 0, "Cannot run on zero threads");
+    len.div_ceil(threads).max(1)
+}
+
+impl<T> LinkedList<T> {
+    fn _segments(&self, threads: usize) -> Vec<Iter<'_, T>> {
+        let segment_len = _segment_len(self.len(), threads);
+        let mut segments = Vec::new();
+        let mut head = self.head;
+        let mut left = self.len();
+        while left > 0 {
+            let len = segment_len.min(left);
+            let mut tail = head;
+            for _ in 1..len {
+                tail = tail.as_mut_unchecked().next;
+            }
+            segments.push(Iter {
+                head,
+                tail,
+                left: len,
+                phantom: PhantomData,
+            });
+            head = tail.as_mut_unchecked().next;
+            left -= len;
+        }
+        segments
+    }
+
+    fn _split_segments(&mut self, threads: usize) -> Vec<LinkedList<T>> {
+        let segment_len = _segment_len(self.len(), threads);
+        let mut segments = Vec::new();
+        while self.len() > segment_len {
+            let rest = self.split_off(segment_len);
+            segments.push(std::mem::replace(self, rest));
+        }
+        segments.push(std::mem::take(self));
+        segments
+    }
+
+    pub fn par_for_each<F>(&self, threads: usize, f: F)
+    where
+        T: Sync,
+        F: Fn(&T) + Sync,
+    {
+        let f = &f;
+        thread::scope(|scope| {
+            for segment in self._segments(threads) {
+                scope.spawn(move || segment.for_each(f));
+            }
+        });
+    }
+
+    pub fn par_for_each_mut<F>(&mut self, threads: usize, f: F)
+    where
+        T: Send,
+        F: Fn(&mut T) + Sync,
+    {
+        let f = &f;
+        let segments = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                ._split_segments(threads)
+                .into_iter()
+                .map(|mut segment| {
+                    scope.spawn(move || {
+                        segment.iter_mut().for_each(f);
+                        segment
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        for mut segment in segments {
+            self.append(&mut segment);
+        }
+    }
+
+    pub fn par_map_collect<U, F>(&self, threads: usize, f: F) -> LinkedList<U>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(&T) -> U + Sync,
+    {
+        let f = &f;
+        let segments = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                ._segments(threads)
+                .into_iter()
+                .map(|segment| scope.spawn(move || segment.map(f).collect::<LinkedList<_>>()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        let mut mapped = LinkedList::new();
+        for mut segment in segments {
+            mapped.append(&mut segment);
+        }
+        mapped
+    }
+
+    pub fn par_sort(&mut self, threads: usize)
+    where
+        T: Ord + Send,
+    {
+        self.par_sort_by(threads, T::cmp);
+    }
+
+    pub fn par_sort_by<F>(&mut self, threads: usize, compare: F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let compare = &compare;
+        let mut segments = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                ._split_segments(threads)
+                .into_iter()
+                .map(|segment| {
+                    scope.spawn(move || {
+                        let mut compare = compare;
+                        _merge_sort(segment, &mut compare)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        while segments.len() > 1 {
+            // merging neighbours keeps the sort stable
+            segments = thread::scope(|scope| {
+                let mut handles = Vec::new();
+                let mut segments = segments.into_iter();
+                while let Some(left) = segments.next() {
+                    match segments.next() {
+                        Some(right) => handles.push(scope.spawn(move || {
+                            let mut compare = compare;
+                            _merge(left, right, &mut compare)
+                        })),
+                        None => handles.push(scope.spawn(move || left)),
+                    }
+                }
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect()
+            });
+        }
+        *self = segments.pop().unwrap_or_default();
+    }
+}
@@ -0,0 +1,258 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+use ::rayon::iter::{
+    plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+    FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelExtend,
+    ParallelIterator,
+};
+
+use super::{_split_point, IntoIter, Iter, IterMut, LinkedList};
+
+pub struct ParIter<'a, T> {
+    iter: Iter<'a, T>,
+}
+
+pub struct ParIterMut<'a, T> {
+    iter: IterMut<'a, T>,
+}
+
+pub struct IntoParIter<T> {
+    list: LinkedList<T>,
+}
+
+struct IterProducer<'a, T> {
+    iter: Iter<'a, T>,
+}
+
+struct IterMutProducer<'a, T> {
+    iter: IterMut<'a, T>,
+}
+
+struct ListProducer<T> {
+    list: LinkedList<T>,
+}
+
+macro_rules! split_iter {
+    ($iter:expr, $at:expr, $Iter:ident) => {{
+        let iter = $iter;
+        let (left_tail, right_head) = _split_point(iter.head, iter.tail, iter.left, $at);
+        (
+            Self {
+                iter: $Iter {
+                    head: iter.head,
+                    tail: left_tail,
+                    left: $at,
+                    phantom: PhantomData,
+                },
+            },
+            Self {
+                iter: $Iter {
+                    head: right_head,
+                    tail: iter.tail,
+                    left: iter.left - $at,
+                    phantom: PhantomData,
+                },
+            },
+        )
+    }};
+}
+
+// ParIter
+
+impl<T: Debug> Debug for ParIter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParIter").field(&self.iter).finish()
+    }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParIter<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.left
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterProducer { iter: self.iter })
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.left)
+    }
+}
+
+impl<'a, T: Sync> Producer for IterProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        split_iter!(self.iter, index, Iter)
+    }
+}
+
+// ParIterMut
+
+impl<T: Debug> Debug for ParIterMut<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ParIterMut").field(&self.iter).finish()
+    }
+}
+
+impl<'a, T: Send> IndexedParallelIterator for ParIterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.left
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterMutProducer { iter: self.iter })
+    }
+}
+
+impl<'a, T: Send> ParallelIterator for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.left)
+    }
+}
+
+impl<'a, T: Send> Producer for IterMutProducer<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        split_iter!(self.iter, index, IterMut)
+    }
+}
+
+// IntoParIter
+
+impl<T: Debug> Debug for IntoParIter<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoParIter").field(&self.list).finish()
+    }
+}
+
+impl<T: Send> IndexedParallelIterator for IntoParIter<T> {
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ListProducer { list: self.list })
+    }
+}
+
+impl<T: Send> ParallelIterator for IntoParIter<T> {
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.list.len())
+    }
+}
+
+impl<T: Send> Producer for ListProducer<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let right = self.list.split_off(index);
+        (self, ListProducer { list: right })
+    }
+}
+
+// LinkedList
+
+impl<'a, T: Sync> IntoParallelIterator for &'a LinkedList<T> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { iter: self.iter() }
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut LinkedList<T> {
+    type Iter = ParIterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut {
+            iter: self.iter_mut(),
+        }
+    }
+}
+
+impl<T: Send> IntoParallelIterator for LinkedList<T> {
+    type Iter = IntoParIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter { list: self }
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for LinkedList<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        par_iter
+            .into_par_iter()
+            .fold(LinkedList::new, |mut list, value| {
+                list.push_back(value);
+                list
+            })
+            .reduce(LinkedList::new, |mut left, mut right| {
+                left.append(&mut right);
+                left
+            })
+    }
+}
+
+impl<T: Send> ParallelExtend<T> for LinkedList<T> {
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        self.append(&mut LinkedList::from_par_iter(par_iter));
+    }
+}
+
+impl<'a, T: Copy + Send + Sync + 'a> ParallelExtend<&'a T> for LinkedList<T> {
+    fn par_extend<I: IntoParallelIterator<Item = &'a T>>(&mut self, par_iter: I) {
+        self.par_extend(par_iter.into_par_iter().copied());
+    }
+}
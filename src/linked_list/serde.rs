@@ -0,0 +1,155 @@
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{LinkedList, ListSnapshot};
+
+const SNAPSHOT_FIELDS: &[&str] = &["list", "index"];
+
+struct ListVisitor<T> {
+    phantom: PhantomData<T>,
+}
+
+struct SnapshotVisitor<T> {
+    phantom: PhantomData<T>,
+}
+
+enum SnapshotField {
+    List,
+    Index,
+}
+
+impl<T: Serialize> Serialize for LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor {
+            phantom: PhantomData,
+        })
+    }
+}
+
+// the length hint is never trusted, nodes are only allocated for elements that actually arrive
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+    type Value = LinkedList<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = LinkedList::new();
+        while let Some(value) = seq.next_element()? {
+            list.push_back(value);
+        }
+        Ok(list)
+    }
+}
+
+// ListSnapshot
+
+impl<T: Serialize> Serialize for ListSnapshot<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ListSnapshot", 2)?;
+        state.serialize_field("list", &self.list)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ListSnapshot<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(
+            "ListSnapshot",
+            SNAPSHOT_FIELDS,
+            SnapshotVisitor {
+                phantom: PhantomData,
+            },
+        )
+    }
+}
+
+impl<T> SnapshotVisitor<T> {
+    fn _build<E: de::Error>(
+        list: LinkedList<T>,
+        index: Option<usize>,
+    ) -> Result<ListSnapshot<T>, E> {
+        match index {
+            Some(i) if i >= list.len() => Err(E::invalid_value(
+                de::Unexpected::Unsigned(i as u64),
+                &"an index within the list bounds",
+            )),
+            _ => Ok(ListSnapshot { list, index }),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for SnapshotVisitor<T> {
+    type Value = ListSnapshot<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct ListSnapshot")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let list = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let index = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::_build(list, index)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut list = None;
+        let mut index = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SnapshotField::List if list.is_some() => {
+                    return Err(de::Error::duplicate_field("list"))
+                }
+                SnapshotField::List => list = Some(map.next_value()?),
+                SnapshotField::Index if index.is_some() => {
+                    return Err(de::Error::duplicate_field("index"))
+                }
+                SnapshotField::Index => index = Some(map.next_value()?),
+            }
+        }
+        let list = list.ok_or_else(|| de::Error::missing_field("list"))?;
+        let index = index.ok_or_else(|| de::Error::missing_field("index"))?;
+        Self::_build(list, index)
+    }
+}
+
+impl<'de> Deserialize<'de> for SnapshotField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = SnapshotField;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("`list` or `index`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                match value {
+                    "list" => Ok(SnapshotField::List),
+                    "index" => Ok(SnapshotField::Index),
+                    _ => Err(de::Error::unknown_field(value, SNAPSHOT_FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
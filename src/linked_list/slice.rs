@@ -0,0 +1,393 @@
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use super::{_merge_sort, _split_point, Cursor, Iter, IterMut, LinkedList, NodePtr};
+
+pub struct ListSlice<'a, T> {
+    pub(super) list: &'a LinkedList<T>,
+    pub(super) start: usize,
+    pub(super) iter: Iter<'a, T>,
+}
+
+pub struct ListSliceMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+    start: usize,
+    head: NodePtr<T>,
+    tail: NodePtr<T>,
+    len: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub(super) fn _range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => Some(self.len()),
+        };
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end && end <= self.len() => (start, end),
+            _ => panic!("Cannot slice outside of the list bounds"),
+        }
+    }
+
+    pub(super) fn _range_pointers(&self, start: usize, end: usize) -> (NodePtr<T>, NodePtr<T>) {
+        if start == end {
+            return Default::default();
+        }
+        (self._pointer_at(start), self._pointer_at(end - 1))
+    }
+
+    fn _link_between(
+        &mut self,
+        mut before: NodePtr<T>,
+        mut after: NodePtr<T>,
+        list: LinkedList<T>,
+    ) {
+        match before.as_mut() {
+            Some(node) => node.next = list.head,
+            None => self.head = list.head,
+        }
+        match after.as_mut() {
+            Some(node) => node.prev = list.tail,
+            None => self.tail = list.tail,
+        }
+        list.head.as_mut_unchecked().prev = before;
+        list.tail.as_mut_unchecked().next = after;
+        self.len += list.len();
+        core::mem::forget(list);
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ListSlice<'_, T> {
+        let (start, end) = self._range(range);
+        let (head, tail) = self._range_pointers(start, end);
+        ListSlice {
+            list: self,
+            start,
+            iter: Iter {
+                head,
+                tail,
+                left: end - start,
+                phantom: PhantomData,
+            },
+        }
+    }
+
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> ListSliceMut<'_, T> {
+        let (start, end) = self._range(range);
+        let (head, tail) = self._range_pointers(start, end);
+        ListSliceMut {
+            list: self,
+            start,
+            head,
+            tail,
+            len: end - start,
+        }
+    }
+}
+
+fn _reverse<T>(list: &mut LinkedList<T>) {
+    let mut node_ptr = list.head;
+    while let Some(node) = node_ptr.as_mut() {
+        core::mem::swap(&mut node.next, &mut node.prev);
+        node_ptr = node.prev;
+    }
+    core::mem::swap(&mut list.head, &mut list.tail);
+}
+
+// ListSlice
+
+impl<'a, T> ListSlice<'a, T> {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter.left
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        self.iter
+    }
+
+    pub fn first(&self) -> Option<&'a T> {
+        self.iter().next()
+    }
+
+    pub fn last(&self) -> Option<&'a T> {
+        self.iter().next_back()
+    }
+
+    pub fn split_at(&self, mid: usize) -> (ListSlice<'a, T>, ListSlice<'a, T>) {
+        assert!(
+            mid <= self.len(),
+            "Cannot split a slice at a nonexistent index"
+        );
+        let (left_tail, right_head) = _split_point(self.iter.head, self.iter.tail, self.len(), mid);
+        (
+            ListSlice {
+                list: self.list,
+                start: self.start,
+                iter: Iter {
+                    head: self.iter.head,
+                    tail: left_tail,
+                    left: mid,
+                    phantom: PhantomData,
+                },
+            },
+            ListSlice {
+                list: self.list,
+                start: self.start + mid,
+                iter: Iter {
+                    head: right_head,
+                    tail: self.iter.tail,
+                    left: self.len() - mid,
+                    phantom: PhantomData,
+                },
+            },
+        )
+    }
+
+    // the cursor starts on the first element of the slice but can move over the whole list
+    pub fn cursor_front(&self) -> Cursor<'a, T> {
+        if self.is_empty() {
+            return Cursor {
+                next_index: 0,
+                current: Default::default(),
+                list: self.list,
+            };
+        }
+        Cursor {
+            next_index: self.start + 1,
+            current: self.iter.head,
+            list: self.list,
+        }
+    }
+}
+
+impl<T> Clone for ListSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListSlice<'_, T> {}
+
+impl<T: Debug> Debug for ListSlice<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Hash> Hash for ListSlice<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len()); // write_length_prefix
+        for elt in self.iter() {
+            elt.hash(state);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for ListSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+}
+
+impl<T: Ord> Ord for ListSlice<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq<T>> PartialEq for ListSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<T>> PartialEq<LinkedList<T>> for ListSlice<'_, T> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        self.iter().eq(other)
+    }
+}
+
+impl<T: PartialOrd<T>> PartialOrd for ListSlice<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ListSlice<'_, T> {}
+
+unsafe impl<T: Sync> Send for ListSlice<'_, T> {}
+
+unsafe impl<T: Sync> Sync for ListSlice<'_, T> {}
+
+// ListSliceMut
+
+impl<T> ListSliceMut<'_, T> {
+    pub fn as_slice(&self) -> ListSlice<'_, T> {
+        ListSlice {
+            list: self.list,
+            start: self.start,
+            iter: Iter {
+                head: self.head,
+                tail: self.tail,
+                left: self.len,
+                phantom: PhantomData,
+            },
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            left: self.len,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().next()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().next_back()
+    }
+
+    pub fn split_at(&self, mid: usize) -> (ListSlice<'_, T>, ListSlice<'_, T>) {
+        self.as_slice().split_at(mid)
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        self.as_slice().cursor_front()
+    }
+
+    // the segment is taken out of the list while `f` runs, so a panic leaves
+    // the list consistent and the slice empty
+    fn _relink_with<F: FnOnce(&mut LinkedList<T>)>(&mut self, f: F) {
+        if self.len < 2 {
+            return;
+        }
+        let before = self.head.as_mut_unchecked().prev;
+        let after = self.tail.as_mut_unchecked().next;
+        let mut segment = self.list._unlink_range(self.head, self.tail, self.len);
+        self.len = 0;
+        f(&mut segment);
+        self.head = segment.head;
+        self.tail = segment.tail;
+        self.len = segment.len();
+        self.list._link_between(before, after, segment);
+    }
+
+    pub fn reverse(&mut self) {
+        self._relink_with(_reverse);
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self._relink_with(|segment| {
+            *segment = _merge_sort(core::mem::take(segment), &mut compare);
+        });
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+}
+
+impl<T: Debug> Debug for ListSliceMut<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Hash> Hash for ListSliceMut<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<'a, T> IntoIterator for ListSliceMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            left: self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Ord> Ord for ListSliceMut<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(&other.as_slice())
+    }
+}
+
+impl<T: PartialEq<T>> PartialEq for ListSliceMut<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<T>> PartialEq<LinkedList<T>> for ListSliceMut<'_, T> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialOrd<T>> PartialOrd for ListSliceMut<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(&other.as_slice())
+    }
+}
+
+impl<T: Eq> Eq for ListSliceMut<'_, T> {}
+
+unsafe impl<T: Send> Send for ListSliceMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for ListSliceMut<'_, T> {}
@@ -0,0 +1,102 @@
+use core::fmt::Debug;
+
+use super::{Cursor, CursorMut, LinkedList, NodePtr};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ListSnapshot<T> {
+    pub(super) list: LinkedList<T>,
+    pub(super) index: Option<usize>,
+}
+
+impl<T> ListSnapshot<T> {
+    pub fn new(list: LinkedList<T>, index: Option<usize>) -> ListSnapshot<T> {
+        assert!(
+            index.is_none_or(|index| index < list.len()),
+            "Cannot snapshot a cursor outside of the list bounds"
+        );
+        ListSnapshot { list, index }
+    }
+
+    pub fn list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    fn _current(&self) -> (NodePtr<T>, usize) {
+        match self.index {
+            Some(index) => (self.list._pointer_at(index), index + 1),
+            None => (NodePtr::default(), 0),
+        }
+    }
+
+    pub fn cursor(&self) -> Cursor<'_, T> {
+        let (current, next_index) = self._current();
+        Cursor {
+            next_index,
+            current,
+            list: &self.list,
+        }
+    }
+
+    pub fn with_cursor_mut<R, F: FnOnce(&mut CursorMut<'_, T>) -> R>(&mut self, f: F) -> R {
+        let (current, next_index) = self._current();
+        let mut cursor = CursorMut {
+            next_index,
+            current,
+            list: &mut self.list,
+        };
+        let result = f(&mut cursor);
+        self.index = cursor.index();
+        result
+    }
+
+    pub fn into_parts(self) -> (LinkedList<T>, Option<usize>) {
+        (self.list, self.index)
+    }
+}
+
+impl<T: Debug> Debug for ListSnapshot<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ListSnapshot")
+            .field("list", &self.list)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<T> Default for ListSnapshot<T> {
+    fn default() -> Self {
+        ListSnapshot {
+            list: LinkedList::new(),
+            index: None,
+        }
+    }
+}
+
+impl<T> From<LinkedList<T>> for ListSnapshot<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        ListSnapshot { list, index: None }
+    }
+}
+
+// Cursor
+
+impl<T: Clone> Cursor<'_, T> {
+    pub fn snapshot(&self) -> ListSnapshot<T> {
+        ListSnapshot {
+            list: self.list.clone(),
+            index: self.index(),
+        }
+    }
+}
+
+// CursorMut
+
+impl<T: Clone> CursorMut<'_, T> {
+    pub fn snapshot(&self) -> ListSnapshot<T> {
+        self.as_cursor().snapshot()
+    }
+}
@@ -1,14 +1,15 @@
 use super::*;
+use crate::test_util::*;
 
 fn iter_cmp_with<I1: IntoIterator, I2: IntoIterator>(
     iter1: I1,
     iter2: I2,
     mut eq: impl FnMut(&I1::Item, &I2::Item) -> bool,
 ) -> bool {
-    let mut iter1 = iter1.into_iter();
+    let iter1 = iter1.into_iter();
     let mut iter2 = iter2.into_iter();
-    while let Some(item1) = iter1.next() {
-        if !iter2.next().map_or(false, |item2| eq(&item1, &item2)) {
+    for item1 in iter1 {
+        if !iter2.next().is_some_and(|item2| eq(&item1, &item2)) {
             return false;
         }
     }
@@ -103,8 +104,928 @@ fn test_iters() {
     assert!(iter_cmp(list.into_iter(), 1..=3));
 }
 
+#[test]
+fn test_index_from_back() {
+    let mut list: LinkedList<_> = (0..10).collect();
+    assert_eq!(list.remove(7), 7);
+    assert_eq!(list.remove(8), 9);
+    assert_eq!(list.split_off(6), [6, 8].into());
+    assert_eq!(list, (0..6).collect());
+    assert_eq!(list.back(), Some(&5));
+}
+
+#[test]
+fn test_insert_before_back_links() {
+    let mut list = LinkedList::from([2, 4]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.insert_before(1);
+    cursor.move_next();
+    cursor.insert_before(3);
+    assert_eq!(cursor.current(), Some(&mut 4));
+    assert_eq!(cursor.peek_prev(), Some(&mut 3));
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&mut 2));
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    assert_eq!(list.cursor_back().index(), Some(3));
+}
+
+#[test]
+fn test_remove_current_as_list() {
+    let mut list = LinkedList::from([1, 2, 3]);
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.remove_current_as_list(), Some([1].into()));
+    assert_eq!(cursor.current(), Some(&mut 2));
+    cursor.move_next();
+    assert_eq!(cursor.remove_current_as_list(), Some([3].into()));
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.remove_current_as_list(), None);
+    assert_eq!(list, [2].into());
+    assert_eq!(list.front(), Some(&2));
+    assert_eq!(list.back(), Some(&2));
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.remove_current_as_list(), Some([2].into()));
+    assert!(list.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_par_for_each() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut list: LinkedList<_> = (0..1000).collect();
+    for threads in [1, 3, 8, 2000] {
+        let sum = AtomicUsize::new(0);
+        list.par_for_each(threads, |&x| {
+            sum.fetch_add(x, Ordering::Relaxed);
+        });
+        assert_eq!(sum.into_inner(), 499500);
+    }
+    list.par_for_each_mut(7, |x| *x *= 2);
+    assert!(iter_cmp(list.iter().copied(), (0..2000).step_by(2)));
+    assert_eq!(list.len(), 1000);
+    assert_eq!(list.back(), Some(&1998));
+    let mut empty = LinkedList::<usize>::new();
+    empty.par_for_each(4, |_| unreachable!());
+    empty.par_for_each_mut(4, |_| unreachable!());
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_par_map_collect() {
+    let list: LinkedList<_> = (0..100).collect();
+    let mapped = list.par_map_collect(6, |x| x.to_string());
+    assert!(iter_cmp(
+        mapped.iter().cloned(),
+        (0..100).map(|x| x.to_string())
+    ));
+    assert_eq!(mapped.back(), Some(&"99".to_string()));
+    assert!(LinkedList::<i32>::new()
+        .par_map_collect(2, |x| *x)
+        .is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_par_sort() {
+    let mut seed = 42;
+    let values: Vec<_> = (0..5000).map(|_| pseudo_random(&mut seed) % 100).collect();
+    for threads in [1, 2, 5, 16] {
+        let mut list: LinkedList<_> = values.iter().copied().enumerate().collect();
+        list.par_sort_by(threads, |a, b| a.1.cmp(&b.1));
+        let mut expected: Vec<_> = values.iter().copied().enumerate().collect();
+        expected.sort_by_key(|x| x.1);
+        assert!(iter_cmp(list.iter().copied(), expected.iter().copied()));
+        assert_eq!(list.back(), expected.last());
+        assert_eq!(list.iter().rev().count(), values.len());
+    }
+    let mut list = LinkedList::from([3, 1, 2]);
+    list.par_sort(8);
+    assert_eq!(list, LinkedList::from([1, 2, 3]));
+    let mut empty = LinkedList::<i32>::new();
+    empty.par_sort(4);
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_rayon() {
+    use ::rayon::prelude::*;
+
+    let mut list: LinkedList<_> = (0..10_000).collect();
+    assert_eq!(list.par_iter().sum::<i64>(), 49_995_000);
+    assert_eq!(list.par_iter().len(), 10_000);
+    assert_eq!(list.par_iter().rev().skip(9_000).copied().max(), Some(999));
+    list.par_iter_mut().for_each(|x| *x *= 3);
+    assert!(iter_cmp(list.iter().copied(), (0..30_000).step_by(3)));
+    let halves: Vec<_> = list
+        .par_iter()
+        .with_min_len(1)
+        .chunks(5_000)
+        .map(|c| c.len())
+        .collect();
+    assert_eq!(halves, [5_000, 5_000]);
+    let strings: LinkedList<_> = list.into_par_iter().map(|x| x.to_string()).collect();
+    assert_eq!(strings.len(), 10_000);
+    assert!(iter_cmp(
+        strings,
+        (0..30_000).step_by(3).map(|x| x.to_string())
+    ));
+    let mut list = LinkedList::from([-2, -1]);
+    list.par_extend((0..1000).into_par_iter());
+    list.par_extend(&[1000, 1001]);
+    assert!(iter_cmp(list.iter().copied(), -2..1002));
+    assert_eq!(list.back(), Some(&1001));
+    let odd: LinkedList<_> = list.into_par_iter().filter(|x| x % 2 != 0).collect();
+    assert!(iter_cmp(odd, (-1..1002).step_by(2)));
+    assert!(LinkedList::<i32>::new()
+        .into_par_iter()
+        .collect::<Vec<_>>()
+        .is_empty());
+}
+
+#[test]
+fn test_snapshot() {
+    let mut list = LinkedList::from([1, 2, 3, 4]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+    let mut snapshot = cursor.snapshot();
+    assert_eq!(snapshot.index(), Some(2));
+    assert_eq!(snapshot.list(), &list);
+    let removed = snapshot.with_cursor_mut(|cursor| {
+        assert_eq!(cursor.current(), Some(&mut 3));
+        cursor.insert_before(5);
+        cursor.move_next();
+        cursor.remove_current()
+    });
+    assert_eq!(removed, Some(4));
+    assert_eq!(snapshot.index(), None);
+    snapshot.with_cursor_mut(|cursor| cursor.move_prev());
+    assert_eq!(snapshot.index(), Some(3));
+    let cursor = snapshot.cursor();
+    assert_eq!(cursor.current(), Some(&3));
+    assert_eq!(cursor.peek_prev(), Some(&5));
+    assert_eq!(snapshot.list(), &LinkedList::from([1, 2, 5, 3]));
+    let ghost = ListSnapshot::from(LinkedList::from([1]));
+    assert_eq!(ghost.cursor().current(), None);
+    assert_eq!(ghost.cursor().peek_next(), Some(&1));
+    let (list, index) = ListSnapshot::new(LinkedList::from([7, 8]), Some(1)).into_parts();
+    assert_eq!((list, index), (LinkedList::from([7, 8]), Some(1)));
+}
+
+#[test]
+#[should_panic]
+fn test_snapshot_out_of_bounds() {
+    ListSnapshot::new(LinkedList::from([1, 2]), Some(2));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+    let list = LinkedList::from([1, 2, 3]);
+    assert_tokens(
+        &list,
+        &[
+            Token::Seq { len: Some(3) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::I32(3),
+            Token::SeqEnd,
+        ],
+    );
+    assert_tokens(
+        &LinkedList::<LinkedList<u8>>::from([[].into(), [4].into()]),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Seq { len: Some(1) },
+            Token::U8(4),
+            Token::SeqEnd,
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens(
+        &LinkedList::from([4, 5]),
+        &[
+            Token::Seq {
+                len: Some(usize::MAX),
+            },
+            Token::I32(4),
+            Token::I32(5),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<LinkedList<i32>>(
+        &[Token::Seq { len: Some(2) }, Token::I32(1), Token::Str("x")],
+        "invalid type: string \"x\", expected i32",
+    );
+    assert_de_tokens_error::<LinkedList<i32>>(
+        &[Token::Map { len: None }],
+        "invalid type: map, expected a sequence",
+    );
+
+    let snapshot = ListSnapshot::new(LinkedList::from([7, 8]), Some(1));
+    let tokens = [
+        Token::Struct {
+            name: "ListSnapshot",
+            len: 2,
+        },
+        Token::Str("list"),
+        Token::Seq { len: Some(2) },
+        Token::I32(7),
+        Token::I32(8),
+        Token::SeqEnd,
+        Token::Str("index"),
+        Token::Some,
+        Token::U64(1),
+        Token::StructEnd,
+    ];
+    assert_tokens(&snapshot, &tokens);
+    assert_eq!(snapshot.cursor().current(), Some(&8));
+    assert_de_tokens(
+        &ListSnapshot::from(LinkedList::from([7])),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Seq { len: Some(1) },
+            Token::I32(7),
+            Token::SeqEnd,
+            Token::None,
+            Token::SeqEnd,
+        ],
+    );
+    let mut out_of_bounds = tokens;
+    out_of_bounds[8] = Token::U64(2);
+    assert_de_tokens_error::<ListSnapshot<i32>>(
+        &out_of_bounds,
+        "invalid value: integer `2`, expected an index within the list bounds",
+    );
+    assert_de_tokens_error::<ListSnapshot<i32>>(
+        &[&tokens[..6], &[Token::StructEnd]].concat(),
+        "missing field `index`",
+    );
+    assert_de_tokens_error::<ListSnapshot<i32>>(
+        &[
+            Token::Struct {
+                name: "ListSnapshot",
+                len: 2,
+            },
+            Token::Str("cursor"),
+        ],
+        "unknown field `cursor`, expected `list` or `index`",
+    );
+}
+
+#[test]
+fn test_cursor_seek() {
+    let list: LinkedList<_> = (0..10).collect();
+    let mut cursor = list.cursor_front();
+    assert!(!cursor.is_ghost());
+    for at in [7, 8, 2, 9, 0, 5, 4, 6] {
+        cursor.seek(at);
+        assert_eq!(cursor.index(), Some(at));
+        assert_eq!(cursor.current(), Some(&at));
+    }
+    cursor.move_to_ghost();
+    assert!(cursor.is_ghost());
+    assert_eq!(cursor.index(), None);
+    cursor.seek(3);
+    assert_eq!(cursor.current(), Some(&3));
+    cursor.move_to_back();
+    assert_eq!((cursor.index(), cursor.current()), (Some(9), Some(&9)));
+    cursor.move_to_front();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&0)));
+
+    let mut list = list;
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_by(4);
+    assert_eq!(cursor.current(), Some(&mut 4));
+    cursor.move_by(-2);
+    assert_eq!(cursor.index(), Some(2));
+    cursor.move_by(8);
+    assert!(cursor.is_ghost());
+    cursor.move_by(1);
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_by(-1);
+    assert!(cursor.is_ghost());
+    cursor.move_by(-1);
+    assert_eq!(cursor.current(), Some(&mut 9));
+    cursor.move_by(11 * 3 + 2);
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_by(isize::MIN);
+    cursor.move_by(isize::MAX);
+    cursor.move_by(1);
+    assert_eq!(cursor.index(), Some(0));
+    cursor.seek(5);
+    cursor.move_by(0);
+    assert_eq!(cursor.current(), Some(&mut 5));
+    cursor.move_to_ghost();
+    cursor.move_next();
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_to_back();
+    cursor.insert_after(10);
+    cursor.move_next();
+    assert_eq!(cursor.index(), Some(10));
+
+    let mut empty = LinkedList::<i32>::new();
+    let mut cursor = empty.cursor_front_mut();
+    cursor.move_by(5);
+    assert!(cursor.is_ghost());
+    cursor.move_to_front();
+    assert!(cursor.is_ghost());
+}
+
+#[test]
+#[should_panic]
+fn test_cursor_seek_out_of_bounds() {
+    let list = LinkedList::from([1, 2]);
+    list.cursor_front().seek(2);
+}
+
+#[test]
+fn test_cursor_find() {
+    let mut list = LinkedList::from([1, 4, 2, 6, 3, 8]);
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.find_next(|x| x % 2 == 0), Some(&4));
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(cursor.find_next(|x| x % 2 == 0), Some(&2));
+    assert_eq!(cursor.find_prev(|&x| x == 1), Some(&1));
+    assert_eq!(cursor.find_prev(|&x| x == 1), None);
+    assert!(cursor.is_ghost());
+    assert_eq!(cursor.find_prev(|&x| x > 5), Some(&8));
+    assert_eq!(cursor.find_next(|&x| x > 10), None);
+    assert!(cursor.is_ghost());
+    assert_eq!(cursor.find_next(|_| true), Some(&1));
+
+    let mut cursor = list.cursor_back_mut();
+    *cursor.find_prev(|&x| x < 4).unwrap() *= 10;
+    assert_eq!(cursor.index(), Some(4));
+    assert_eq!(cursor.find_next(|&x| x == 30), None);
+    assert_eq!(cursor.find_next(|&x| x == 30), Some(&mut 30));
+    assert_eq!(list, LinkedList::from([1, 4, 2, 6, 30, 8]));
+}
+
+#[test]
+fn test_find_subsequence() {
+    let list = LinkedList::from([1, 2, 1, 2, 1, 3, 1, 2, 1, 2, 1, 2, 3]);
+    let cursor = list.find_subsequence(&[1, 2, 1, 3]).unwrap();
+    assert_eq!(cursor.index(), Some(2));
+    assert_eq!(cursor.current(), Some(&1));
+    assert_eq!(list.find_subsequence(&[1, 2, 3]).unwrap().index(), Some(10));
+    assert_eq!(list.find_subsequence(&[2, 3]).unwrap().index(), Some(11));
+    assert_eq!(list.find_subsequence(&[3]).unwrap().index(), Some(5));
+    assert!(list.find_subsequence(&[3, 3]).is_none());
+    assert!(list.contains_subsequence(&[2, 1, 2, 1, 2]));
+    assert!(!list.contains_subsequence(&[1, 1]));
+    assert!(!list.contains_subsequence(&[0; 20]));
+    assert_eq!(list.find_subsequence(&[]).unwrap().index(), Some(0));
+    let empty = LinkedList::<i32>::new();
+    assert!(empty.contains_subsequence(&[]));
+    assert!(!empty.contains_subsequence(&[1]));
+
+    let mut seed = 7;
+    let list: LinkedList<_> = (0..500).map(|_| pseudo_random(&mut seed) % 3).collect();
+    let values: Vec<_> = list.iter().copied().collect();
+    for len in 1..6 {
+        for start in (0..values.len() - len).step_by(37) {
+            let pattern = &values[start..start + len];
+            let expected = values.windows(len).position(|window| window == pattern);
+            let found = list
+                .find_subsequence(pattern)
+                .and_then(|cursor| cursor.index());
+            assert_eq!(found, expected);
+        }
+    }
+}
+
+#[test]
+fn test_cursor_multi_edits() {
+    let mut list: LinkedList<_> = (0..10).collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    assert_eq!(cursor.remove_next_n(3), LinkedList::from([2, 3, 4]));
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(cursor.peek_next(), Some(&mut 5));
+    assert_eq!(cursor.remove_next_n(0), LinkedList::new());
+    assert_eq!(
+        cursor.remove_until(|&x| x == 8),
+        LinkedList::from([5, 6, 7])
+    );
+    assert_eq!(cursor.remove_until(|&x| x == 8), LinkedList::new());
+    cursor.insert_iter_before([10, 11]);
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(cursor.current(), Some(&mut 1));
+    cursor.insert_iter_after(12..14);
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(cursor.replace_current(20), 1);
+    cursor.move_to_back();
+    assert_eq!(cursor.remove_next_n(5), LinkedList::new());
+    assert_eq!(cursor.remove_until(|_| false), LinkedList::new());
+    cursor.move_to_ghost();
+    assert_eq!(cursor.remove_next_n(2), LinkedList::from([0, 10]));
+    assert_eq!(list, LinkedList::from([11, 20, 12, 13, 8, 9]));
+    assert_eq!(list.back(), Some(&9));
+
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(
+        cursor.remove_next_n(100),
+        LinkedList::from([20, 12, 13, 8, 9])
+    );
+    assert_eq!(cursor.remove_until(|_| false), LinkedList::new());
+    cursor.move_to_ghost();
+    cursor.insert_iter_before([1, 2]);
+    cursor.insert_iter_after([0]);
+    assert_eq!(
+        cursor.remove_until(|_| false),
+        LinkedList::from([0, 11, 1, 2])
+    );
+    assert!(list.is_empty());
+    assert_eq!((list.front(), list.back()), (None, None));
+}
+
+#[test]
+#[should_panic]
+fn test_cursor_replace_ghost() {
+    let mut list = LinkedList::from([1]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_to_ghost();
+    cursor.replace_current(2);
+}
+
+#[test]
+fn test_cursor_node_moves() {
+    let mut list: LinkedList<_> = (0..5).collect();
+    let addresses: Vec<_> = list.iter().map(|x| x as *const i32).collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.seek(2);
+    cursor.move_current_to_front();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 2)));
+    cursor.seek(3);
+    cursor.move_current_to_back();
+    assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&mut 3)));
+    cursor.swap_with_next();
+    assert_eq!(cursor.index(), Some(4));
+    cursor.swap_with_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(3), Some(&mut 3)));
+    cursor.move_to_front();
+    cursor.swap_with_prev();
+    assert_eq!(cursor.index(), Some(0));
+    cursor.swap_with_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&mut 2)));
+    cursor.move_to_ghost();
+    cursor.move_current_to_front();
+    cursor.swap_with_next();
+    assert!(cursor.is_ghost());
+    assert_eq!(list, LinkedList::from([0, 2, 1, 3, 4]));
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        [4, 3, 1, 2, 0]
+    );
+    let mut sorted: Vec<_> = list.iter().map(|x| x as *const i32).collect();
+    sorted.sort_by_key(|&ptr| unsafe { *ptr });
+    assert_eq!(sorted, addresses);
+
+    let mut other = LinkedList::from([10, 11]);
+    let mut cursor = list.cursor_front_mut();
+    let mut other_cursor = other.cursor_back_mut();
+    cursor.seek(1);
+    cursor.transfer_current(&mut other_cursor, Position::Before);
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&mut 1)));
+    assert_eq!(other_cursor.index(), Some(2));
+    assert_eq!(other_cursor.peek_prev(), Some(&mut 2));
+    cursor.move_to_back();
+    cursor.transfer_current(&mut other_cursor, Position::After);
+    assert!(cursor.is_ghost());
+    assert_eq!(other_cursor.peek_next(), Some(&mut 4));
+    cursor.transfer_current(&mut other_cursor, Position::After);
+    other_cursor.move_to_ghost();
+    cursor.move_to_front();
+    cursor.transfer_current(&mut other_cursor, Position::Before);
+    cursor.transfer_current(&mut other_cursor, Position::After);
+    assert_eq!(cursor.current(), Some(&mut 3));
+    assert_eq!(list, LinkedList::from([3]));
+    assert_eq!(other, LinkedList::from([1, 10, 2, 11, 4, 0]));
+    assert_eq!(other.back(), Some(&0));
+
+    let mut empty = LinkedList::new();
+    list.cursor_front_mut()
+        .transfer_current(&mut empty.cursor_front_mut(), Position::After);
+    assert!(list.is_empty());
+    assert_eq!(empty, LinkedList::from([3]));
+}
+
+#[test]
+fn test_cursor_detach() {
+    let mut list: LinkedList<_> = (0..4).collect();
+    let address = list.back().unwrap() as *const i32;
+    let mut cursor = list.cursor_back_mut();
+    let mut node = cursor.detach_current().unwrap();
+    assert!(cursor.is_ghost());
+    assert!(cursor.detach_current().is_none());
+    *node.get_mut() += 10;
+    assert_eq!(node.get(), &13);
+    cursor.move_to_front();
+    cursor.attach_after(node);
+    assert_eq!(cursor.peek_next(), Some(&mut 13));
+    cursor.move_next();
+    let node = cursor.detach_current().unwrap();
+    assert_eq!(cursor.current(), Some(&mut 1));
+    assert_eq!(format!("{:?}", node), "DetachedNode(13)");
+    cursor.attach_before(node);
+    assert_eq!(cursor.index(), Some(2));
+    assert_eq!(list, LinkedList::from([0, 13, 1, 2]));
+    assert_eq!(list.iter().nth(1).unwrap() as *const i32, address);
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        [2, 1, 13, 0]
+    );
+
+    let mut cursor = list.cursor_front_mut();
+    let node = cursor.detach_current().unwrap();
+    let copy = node.clone();
+    let mut single = LinkedList::from(node);
+    assert_eq!(single, LinkedList::from([0]));
+    single.push_back(5);
+    assert_eq!(single.back(), Some(&5));
+    let mut empty = LinkedList::new();
+    empty.cursor_front_mut().attach_before(copy);
+    empty.cursor_front_mut().attach_before(DetachedNode::new(7));
+    assert_eq!(empty, LinkedList::from([7, 0]));
+    let mut strings = LinkedList::from([String::from("a")]);
+    let node = strings.cursor_front_mut().detach_current().unwrap();
+    assert!(strings.is_empty());
+    assert_eq!(node.into_inner(), "a");
+    drop(DetachedNode::new(String::from("b")));
+}
+
+#[test]
+fn test_cursor_iters() {
+    let mut list: LinkedList<_> = (0..6).collect();
+    let mut cursor = list.cursor_front();
+    assert_eq!(
+        cursor.iter_forward().collect::<Vec<_>>(),
+        [&1, &2, &3, &4, &5]
+    );
+    assert_eq!(cursor.iter_backward().len(), 0);
+    cursor.seek(3);
+    assert_eq!(cursor.iter_forward().rev().collect::<Vec<_>>(), [&5, &4]);
+    assert_eq!(cursor.iter_backward().collect::<Vec<_>>(), [&2, &1, &0]);
+    assert_eq!(cursor.iter_backward().next_back(), Some(&0));
+    cursor.move_to_back();
+    cursor.move_next();
+    assert!(cursor.is_ghost());
+    assert_eq!(cursor.iter_forward().len(), 6);
+    assert_eq!(cursor.iter_backward().next(), Some(&5));
+    cursor.move_to_ghost();
+    assert_eq!(cursor.iter_forward().next(), Some(&0));
+
+    let mut start = list.cursor_front();
+    start.seek(1);
+    let mut end = list.cursor_back();
+    end.seek(4);
+    assert_eq!(start.iter_to(&end).collect::<Vec<_>>(), [&1, &2, &3]);
+    assert_eq!(start.iter_to(&start).len(), 0);
+    end.move_to_ghost();
+    assert_eq!(
+        start.iter_to(&end).rev().collect::<Vec<_>>(),
+        [&5, &4, &3, &2, &1]
+    );
+    assert_eq!(end.iter_to(&end).next(), None);
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.seek(2);
+    cursor.iter_mut_forward().for_each(|x| *x *= 10);
+    assert_eq!(cursor.current(), Some(&mut 2));
+    cursor.move_to_ghost();
+    assert_eq!(cursor.iter_mut_forward().len(), 6);
+    assert_eq!(list, LinkedList::from([0, 1, 2, 30, 40, 50]));
+}
+
+#[test]
+#[should_panic(expected = "Cursors are out of order")]
+fn test_cursor_iter_to_order() {
+    let list: LinkedList<_> = (0..3).collect();
+    let start = list.cursor_back();
+    start.iter_to(&list.cursor_front()).count();
+}
+
+#[test]
+fn test_slice() {
+    let list: LinkedList<_> = (0..8).collect();
+    let slice = list.slice(2..6);
+    assert_eq!(slice.len(), 4);
+    assert_eq!((slice.first(), slice.last()), (Some(&2), Some(&5)));
+    assert_eq!(slice, LinkedList::from([2, 3, 4, 5]));
+    assert_eq!(slice.iter().rev().collect::<Vec<_>>(), [&5, &4, &3, &2]);
+    assert_eq!(format!("{:?}", slice), "[2, 3, 4, 5]");
+    let (left, right) = slice.split_at(1);
+    assert_eq!(left, LinkedList::from([2]));
+    assert_eq!(right, LinkedList::from([3, 4, 5]));
+    assert!(left < right);
+    assert_eq!(right.cursor_front().index(), Some(3));
+    assert!(slice.split_at(4).1.is_empty());
+    assert!(slice.split_at(4).1.cursor_front().is_ghost());
+    assert_eq!(list.slice(..=1), list.slice(..2));
+    assert_eq!(list.slice(6..), LinkedList::from([6, 7]));
+    assert!(list.slice(3..3).first().is_none());
+
+    let mut hashes = std::collections::HashSet::new();
+    hashes.insert(list.slice(..3));
+    assert!(hashes.contains(&list.slice(0..3)));
+    assert!(!hashes.contains(&list.slice(1..4)));
+    let other = LinkedList::from([0, 1, 2]);
+    assert!(hashes.contains(&other.slice(..)));
+}
+
+#[test]
+fn test_slice_mut() {
+    let mut list = LinkedList::from([5, 4, 3, 9, 8, 7, 1, 0]);
+    let mut slice = list.slice_mut(3..6);
+    slice.sort();
+    assert_eq!(slice, LinkedList::from([7, 8, 9]));
+    slice.reverse();
+    *slice.first_mut().unwrap() += 10;
+    slice.iter_mut().for_each(|x| *x *= 2);
+    assert_eq!(slice.cursor_front().index(), Some(3));
+    assert_eq!(list, LinkedList::from([5, 4, 3, 38, 16, 14, 1, 0]));
+
+    let mut slice = list.slice_mut(..);
+    slice.sort_by_key(|&x| x % 10);
+    assert_eq!(slice.last(), Some(&38));
+    assert_eq!(list, LinkedList::from([0, 1, 3, 4, 14, 5, 16, 38]));
+    assert_eq!(list.back(), Some(&38));
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        [38, 16, 5, 14, 4, 3, 1, 0]
+    );
+    list.slice_mut(..4).reverse();
+    list.slice_mut(6..).reverse();
+    list.slice_mut(2..2).sort();
+    assert_eq!(list, LinkedList::from([4, 3, 1, 0, 14, 5, 38, 16]));
+    assert_eq!(list.front(), Some(&4));
+    assert_eq!(list.back(), Some(&16));
+    assert_eq!(list.len(), 8);
+
+    let mut list: LinkedList<_> = (0..5).collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut slice = list.slice_mut(1..4);
+        slice.sort_by(|_, _| panic!());
+    }));
+    assert!(result.is_err());
+    assert_eq!(list.len(), list.iter().count());
+    assert_eq!(list.front(), Some(&0));
+}
+
+#[test]
+#[should_panic(expected = "Cannot slice outside of the list bounds")]
+fn test_slice_inclusive_end_overflow() {
+    LinkedList::from([1]).slice(..=usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Cannot slice outside of the list bounds")]
+fn test_slice_exclusive_start_overflow() {
+    use core::ops::Bound;
+
+    LinkedList::from([1]).slice_mut((Bound::Excluded(usize::MAX), Bound::Unbounded));
+}
+
+#[test]
+fn test_chunks() {
+    let list: LinkedList<_> = (0..7).collect();
+    let collect = |chunk: ListSlice<'_, i32>| chunk.iter().copied().collect::<Vec<_>>();
+    let chunks = list.chunks(3);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        chunks.map(collect).collect::<Vec<_>>(),
+        [vec![0, 1, 2], vec![3, 4, 5], vec![6]]
+    );
+    assert_eq!(
+        list.chunks(3).rev().map(collect).collect::<Vec<_>>(),
+        [vec![6], vec![3, 4, 5], vec![0, 1, 2]]
+    );
+    assert_eq!(list.chunks(7).count(), 1);
+    assert_eq!(list.chunks(10).next().unwrap().len(), 7);
+
+    let mut exact = list.chunks_exact(2);
+    assert_eq!(exact.remainder(), LinkedList::from([6]));
+    assert_eq!(exact.len(), 3);
+    assert_eq!(exact.next_back().map(collect), Some(vec![4, 5]));
+    assert_eq!(
+        exact.map(collect).collect::<Vec<_>>(),
+        [vec![0, 1], vec![2, 3]]
+    );
+    assert!(list.chunks_exact(8).next().is_none());
+
+    assert_eq!(
+        list.rchunks(3).map(collect).collect::<Vec<_>>(),
+        [vec![4, 5, 6], vec![1, 2, 3], vec![0]]
+    );
+    assert_eq!(
+        list.rchunks(3).rev().map(collect).collect::<Vec<_>>(),
+        [vec![0], vec![1, 2, 3], vec![4, 5, 6]]
+    );
+    assert_eq!(
+        list.rchunks(3).nth(1).unwrap().cursor_front().index(),
+        Some(1)
+    );
+
+    let mut windows = list.windows(5);
+    assert_eq!(windows.len(), 3);
+    assert_eq!(windows.next().map(collect), Some(vec![0, 1, 2, 3, 4]));
+    assert_eq!(windows.next_back().map(collect), Some(vec![2, 3, 4, 5, 6]));
+    assert_eq!(windows.next_back().map(collect), Some(vec![1, 2, 3, 4, 5]));
+    assert!(windows.next().is_none());
+    assert_eq!(
+        list.windows(1).map(|w| *w.first().unwrap()).sum::<i32>(),
+        21
+    );
+    assert_eq!(
+        list.windows(2).next_back().unwrap().cursor_front().index(),
+        Some(5)
+    );
+    assert_eq!(list.windows(8).count(), 0);
+    assert_eq!(LinkedList::<i32>::new().chunks(2).count(), 0);
+
+    let chunks = list.clone().into_iter().chunks(3);
+    assert_eq!(chunks.len(), 3);
+    let chunks: Vec<_> = chunks.collect();
+    assert_eq!(
+        chunks,
+        [
+            LinkedList::from([0, 1, 2]),
+            LinkedList::from([3, 4, 5]),
+            LinkedList::from([6])
+        ]
+    );
+    assert_eq!(chunks[1].back(), Some(&5));
+}
+
+#[test]
+#[should_panic(expected = "Cannot split into chunks of size zero")]
+fn test_chunks_zero() {
+    LinkedList::from([1]).chunks(0);
+}
+
+#[test]
+fn test_iter_skipping() {
+    let mut list: LinkedList<_> = (0..10).collect();
+    let mut iter = list.iter();
+    assert_eq!(iter.nth(2), Some(&2));
+    assert_eq!(iter.nth_back(1), Some(&8));
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.advance_by(2), Ok(()));
+    assert_eq!(iter.advance_back_by(1), Ok(()));
+    assert_eq!(iter.collect::<Vec<_>>(), [&5, &6]);
+    assert_eq!(iter.advance_by(5), Err(NonZeroUsize::new(3).unwrap()));
+    assert_eq!(iter.next(), None);
+    assert_eq!(list.iter().nth(10), None);
+    assert_eq!(list.iter().nth_back(9), Some(&0));
+    assert_eq!(list.iter().count(), 10);
+    assert_eq!(list.iter().fold(0, |acc, x| acc * 2 + x), 1013);
+    assert_eq!(list.iter().rfold(0, |acc, x| acc * 2 + x), 8194);
+    let mut iter = list.iter();
+    iter.advance_back_by(7).unwrap();
+    assert_eq!(
+        iter.rfold(Vec::new(), |mut acc, x| {
+            acc.push(*x);
+            acc
+        }),
+        [2, 1, 0]
+    );
+
+    let mut iter = list.iter_mut();
+    *iter.nth(7).unwrap() = 70;
+    *iter.nth_back(0).unwrap() = 90;
+    assert_eq!(iter.advance_back_by(2), Err(NonZeroUsize::new(1).unwrap()));
+    assert_eq!(iter.next(), None);
+    let mut iter = list.iter_mut();
+    iter.advance_by(8).unwrap();
+    iter.fold((), |(), x| *x += 1);
+    assert_eq!(list, LinkedList::from([0, 1, 2, 3, 4, 5, 6, 70, 9, 91]));
+
+    let mut iter = list.into_iter();
+    assert_eq!(iter.nth(1), Some(1));
+    assert_eq!(iter.nth_back(2), Some(70));
+    let clone = iter.clone();
+    assert_eq!(iter.advance_by(2), Ok(()));
+    assert_eq!(iter.as_list(), &LinkedList::from([4, 5, 6]));
+    assert_eq!(iter.advance_back_by(4), Err(NonZeroUsize::new(1).unwrap()));
+    assert!(iter.into_list().is_empty());
+    assert_eq!(clone.count(), 5);
+    let mut iter = LinkedList::from([String::from("a"), String::from("b")]).into_iter();
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.len(), 0);
+    let list: LinkedList<_> = (0..10).collect();
+    assert_eq!(list.clone().into_iter().fold(0, |acc, x| acc * 2 + x), 1013);
+    assert_eq!(
+        list.clone().into_iter().rfold(0, |acc, x| acc * 2 + x),
+        8194
+    );
+    let mut iter = list.into_iter().map(|x| format!("{x}"));
+    iter.nth(6);
+    assert_eq!(iter.rfold(String::new(), |acc, x| acc + &x), "987");
+}
+
+#[test]
+fn test_extract_if() {
+    let mut list: LinkedList<_> = (0..10).collect();
+    let mut iter = list.drain_filter(|x| *x % 3 == 0);
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.size_hint(), (0, Some(8)));
+    assert_eq!(iter.next_back(), Some(6));
+    assert_eq!(iter.size_hint(), (0, Some(5)));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    drop(iter);
+    assert_eq!(list, LinkedList::from([1, 2, 4, 5, 7, 8]));
+    assert_eq!(list.iter().rev().count(), 6);
+
+    let extracted: Vec<_> = list.extract_if(1..4, |x| *x % 2 == 0).rev().collect();
+    assert_eq!(extracted, [4, 2]);
+    assert_eq!(list, LinkedList::from([1, 5, 7, 8]));
+    assert_eq!(list.extract_if(..0, |_| true).count(), 0);
+    assert_eq!(list.extract_if(3.., |_| true).collect::<Vec<_>>(), [8]);
+    assert_eq!(list.back(), Some(&7));
+
+    let mut list: LinkedList<_> = (0..10).collect();
+    let mut visited = 0;
+    let mut iter = list.extract_if(2..8, |_| {
+        visited += 1;
+        true
+    });
+    assert_eq!(iter.size_hint(), (0, Some(6)));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next_back(), Some(7));
+    iter.keep_rest();
+    assert_eq!(visited, 2);
+    assert_eq!(list, LinkedList::from([0, 1, 3, 4, 5, 6, 8, 9]));
+    list.extract_if(.., |x| *x > 4).keep_rest();
+    assert_eq!(list.len(), 8);
+}
+
+// checked at compile time; a failing case does not build
+#[test]
+fn test_auto_traits() {
+    use core::cell::Cell;
+    use std::sync::MutexGuard;
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+    macro_rules! assert_not_impl {
+        ($ty:ty, $trait:path) => {{
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+            struct Invalid;
+            impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+            let _ = <$ty as AmbiguousIfImpl<_>>::some_item;
+        }};
+    }
+    type SendOnly = Cell<u8>;
+    type SyncOnly = MutexGuard<'static, u8>;
+
+    assert_send::<LinkedList<SendOnly>>();
+    assert_not_impl!(LinkedList<SendOnly>, Sync);
+    assert_sync::<LinkedList<SyncOnly>>();
+    assert_not_impl!(LinkedList<SyncOnly>, Send);
+    assert_send::<IntoIter<SendOnly>>();
+    assert_not_impl!(IntoIter<SyncOnly>, Send);
+
+    assert_send::<Iter<'static, SyncOnly>>();
+    assert_sync::<Iter<'static, SyncOnly>>();
+    assert_not_impl!(Iter<'static, SendOnly>, Send);
+    assert_send::<IterMut<'static, SendOnly>>();
+    assert_not_impl!(IterMut<'static, SendOnly>, Sync);
+    assert_sync::<IterMut<'static, SyncOnly>>();
+    assert_not_impl!(IterMut<'static, SyncOnly>, Send);
+
+    assert_send::<Cursor<'static, u8>>();
+    assert_send::<Cursor<'static, SyncOnly>>();
+    assert_sync::<Cursor<'static, SyncOnly>>();
+    assert_not_impl!(Cursor<'static, SendOnly>, Send);
+    assert_send::<CursorMut<'static, SendOnly>>();
+    assert_not_impl!(CursorMut<'static, SendOnly>, Sync);
+    assert_not_impl!(CursorMut<'static, SyncOnly>, Send);
+}
+
 // TODO add more tests
 
+#[allow(
+    dropping_copy_types,
+    clippy::clone_on_copy,
+    clippy::drop_non_drop,
+    clippy::manual_next_back,
+    clippy::neg_cmp_op_on_partial_ord,
+    clippy::zero_divided_by_zero
+)]
 mod std_tests {
 
     // https://github.com/rust-lang/rust/blob/a4bf36e87bdec61240fb3040774d008c70acbfbb/library/alloc/src/collections/linked_list/tests.rs
@@ -0,0 +1,224 @@
+use core::fmt::Debug;
+
+use crate::linked_list::LinkedList;
+
+#[cfg(test)]
+mod tests;
+
+pub trait Strategy {
+    // how many places to move the node at `index` towards the front after an access,
+    // `preceding` yields the access counts of the nodes before it, nearest first
+    fn promote<I: Iterator<Item = u64>>(&mut self, index: usize, count: u64, preceding: I)
+        -> usize;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MoveToFront;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Transpose;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrequencyCount;
+
+impl Strategy for MoveToFront {
+    fn promote<I: Iterator<Item = u64>>(&mut self, index: usize, _: u64, _: I) -> usize {
+        index
+    }
+}
+
+impl Strategy for Transpose {
+    fn promote<I: Iterator<Item = u64>>(&mut self, index: usize, _: u64, _: I) -> usize {
+        index.min(1)
+    }
+}
+
+impl Strategy for FrequencyCount {
+    fn promote<I: Iterator<Item = u64>>(&mut self, _: usize, count: u64, preceding: I) -> usize {
+        preceding.take_while(|&other| other < count).count()
+    }
+}
+
+struct Entry<T> {
+    value: T,
+    count: u64,
+}
+
+pub struct SelfOrganizingList<T, S = MoveToFront> {
+    list: LinkedList<Entry<T>>,
+    strategy: S,
+    accesses: u64,
+    cost: u64,
+}
+
+impl<T, S: Default> SelfOrganizingList<T, S> {
+    pub fn new() -> SelfOrganizingList<T, S> {
+        Self::with_strategy(S::default())
+    }
+}
+
+impl<T, S> SelfOrganizingList<T, S> {
+    pub fn with_strategy(strategy: S) -> SelfOrganizingList<T, S> {
+        SelfOrganizingList {
+            list: LinkedList::new(),
+            strategy,
+            accesses: 0,
+            cost: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    pub fn accesses(&self) -> u64 {
+        self.accesses
+    }
+
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    pub fn reset_counters(&mut self) {
+        self.accesses = 0;
+        self.cost = 0;
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front(Entry { value, count: 0 });
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back(Entry { value, count: 0 });
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.list.iter().map(|entry| &entry.value)
+    }
+
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list.into_iter().map(|entry| entry.value).collect()
+    }
+
+    pub fn remove(&mut self, mut pred: impl FnMut(&T) -> bool) -> Option<T> {
+        self.accesses += 1;
+        let mut cursor = self.list.cursor_front_mut();
+        while let Some(entry) = cursor.current() {
+            self.cost += 1;
+            if pred(&entry.value) {
+                return cursor.remove_current().map(|entry| entry.value);
+            }
+            cursor.move_next();
+        }
+        None
+    }
+}
+
+impl<T, S: Strategy> SelfOrganizingList<T, S> {
+    pub fn find(&mut self, mut pred: impl FnMut(&T) -> bool) -> Option<&mut T> {
+        self.accesses += 1;
+        let mut cursor = self.list.cursor_front_mut();
+        while let Some(entry) = cursor.current() {
+            self.cost += 1;
+            if pred(&entry.value) {
+                break;
+            }
+            cursor.move_next();
+        }
+        let index = cursor.index()?;
+        let entry = cursor.current()?;
+        entry.count += 1;
+        let count = entry.count;
+        let mut before = cursor.as_cursor();
+        let preceding = core::iter::from_fn(|| {
+            before.move_prev();
+            before.current().map(|entry| entry.count)
+        });
+        let steps = self.strategy.promote(index, count, preceding.take(index));
+        let node_ptr = cursor.current;
+        if steps >= index && index > 0 {
+            let node = cursor.remove_current_as_list()?;
+            self.list.cursor_front_mut().splice_before(node);
+        } else if steps > 0 {
+            let node = cursor.remove_current_as_list()?;
+            (0..steps).for_each(|_| cursor.move_prev());
+            cursor.splice_before(node);
+        }
+        Some(&mut node_ptr.as_mut_unchecked().value.value)
+    }
+
+    pub fn get(&mut self, key: &T) -> Option<&mut T>
+    where
+        T: PartialEq,
+    {
+        self.find(|value| value == key)
+    }
+
+    pub fn contains(&mut self, key: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.get(key).is_some()
+    }
+}
+
+impl<T: Clone, S: Clone> Clone for SelfOrganizingList<T, S> {
+    fn clone(&self) -> Self {
+        SelfOrganizingList {
+            list: self
+                .list
+                .iter()
+                .map(|entry| Entry {
+                    value: entry.value.clone(),
+                    count: entry.count,
+                })
+                .collect(),
+            strategy: self.strategy.clone(),
+            accesses: self.accesses,
+            cost: self.cost,
+        }
+    }
+}
+
+impl<T: Debug, S: Debug> Debug for SelfOrganizingList<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SelfOrganizingList")
+            .field("list", &self.iter().collect::<LinkedList<_>>())
+            .field("strategy", &self.strategy)
+            .field("accesses", &self.accesses)
+            .field("cost", &self.cost)
+            .finish()
+    }
+}
+
+impl<T, S: Default> Default for SelfOrganizingList<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S> Extend<T> for SelfOrganizingList<T, S> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elt| self.push_back(elt));
+    }
+}
+
+impl<T, S: Default> FromIterator<T> for SelfOrganizingList<T, S> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
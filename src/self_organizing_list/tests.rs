@@ -0,0 +1,95 @@
+use super::*;
+use crate::test_util::*;
+
+fn order<T: Clone, S>(list: &SelfOrganizingList<T, S>) -> Vec<T> {
+    list.iter().cloned().collect()
+}
+
+#[test]
+fn test_empty() {
+    let mut list = SelfOrganizingList::<i32>::new();
+    assert!(list.is_empty());
+    assert_eq!(list.get(&1), None);
+    assert_eq!(list.remove(|_| true), None);
+    assert_eq!(list.accesses(), 2);
+    assert_eq!(list.cost(), 0);
+}
+
+#[test]
+fn test_move_to_front() {
+    let mut list: SelfOrganizingList<_, MoveToFront> = (1..=5).collect();
+    assert_eq!(list.get(&4), Some(&mut 4));
+    assert_eq!(order(&list), [4, 1, 2, 3, 5]);
+    assert_eq!(list.get(&5), Some(&mut 5));
+    assert_eq!(order(&list), [5, 4, 1, 2, 3]);
+    assert_eq!(list.get(&5), Some(&mut 5));
+    assert_eq!(order(&list), [5, 4, 1, 2, 3]);
+    assert_eq!(list.get(&6), None);
+    assert_eq!(list.accesses(), 4);
+    assert_eq!(list.cost(), 4 + 5 + 1 + 5);
+}
+
+#[test]
+fn test_transpose() {
+    let mut list: SelfOrganizingList<_, Transpose> = (1..=5).collect();
+    list.get(&4);
+    assert_eq!(order(&list), [1, 2, 4, 3, 5]);
+    list.get(&4);
+    list.get(&4);
+    list.get(&4);
+    assert_eq!(order(&list), [4, 1, 2, 3, 5]);
+    list.get(&5);
+    assert_eq!(order(&list), [4, 1, 2, 5, 3]);
+}
+
+#[test]
+fn test_frequency_count() {
+    let mut list: SelfOrganizingList<_, FrequencyCount> = (1..=5).collect();
+    list.get(&3);
+    assert_eq!(order(&list), [3, 1, 2, 4, 5]);
+    list.get(&5);
+    assert_eq!(order(&list), [3, 5, 1, 2, 4]);
+    list.get(&5);
+    assert_eq!(order(&list), [5, 3, 1, 2, 4]);
+    list.get(&3);
+    assert_eq!(order(&list), [5, 3, 1, 2, 4]);
+    list.get(&4);
+    assert_eq!(order(&list), [5, 3, 4, 1, 2]);
+}
+
+#[test]
+fn test_find_mut() {
+    let mut list: SelfOrganizingList<_> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    let entry = list.find(|&(key, _)| key == 3).unwrap();
+    entry.1 = 'z';
+    assert_eq!(order(&list), [(3, 'z'), (1, 'a'), (2, 'b')]);
+    assert_eq!(list.remove(|&(key, _)| key == 1), Some((1, 'a')));
+    assert_eq!(list.into_list(), [(3, 'z'), (2, 'b')].into());
+}
+
+#[test]
+fn test_compare_strategies() {
+    // a skewed trace where a few keys dominate
+    let trace: Vec<_> = (0..2000u64)
+        .map(|i| 19 - (i * i + 7 * i) % 13 % 4 * (i % 3))
+        .collect();
+    let mut mtf: SelfOrganizingList<_, MoveToFront> = (0..20).collect();
+    let mut transpose: SelfOrganizingList<_, Transpose> = (0..20).collect();
+    let mut count: SelfOrganizingList<_, FrequencyCount> = (0..20).collect();
+    let mut baseline = 0;
+    for key in &trace {
+        assert!(mtf.contains(key));
+        assert!(transpose.contains(key));
+        assert!(count.contains(key));
+        baseline += key + 1;
+    }
+    for cost in [mtf.cost(), transpose.cost(), count.cost()] {
+        assert!(cost < baseline);
+    }
+    assert_eq!(mtf.accesses(), trace.len() as u64);
+    mtf.reset_counters();
+    assert_eq!((mtf.accesses(), mtf.cost()), (0, 0));
+    let mut sorted = order(&count);
+    sorted.sort();
+    assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+}
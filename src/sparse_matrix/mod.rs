@@ -0,0 +1,397 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::Debug,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Add, Mul},
+};
+
+use crate::linked_list::LinkPtr;
+
+#[cfg(test)]
+mod tests;
+
+const ROW: usize = 0;
+const COL: usize = 1;
+
+type EntryPtr<T> = LinkPtr<Entry<T>>;
+
+// every entry sits in its row list (ROW links) and its column list (COL links)
+struct Entry<T> {
+    value: T,
+    index: [usize; 2],
+    prev: [EntryPtr<T>; 2],
+    next: [EntryPtr<T>; 2],
+}
+
+struct Line<T> {
+    head: EntryPtr<T>,
+    tail: EntryPtr<T>,
+    len: usize,
+}
+
+impl<T> Default for Line<T> {
+    fn default() -> Self {
+        Line {
+            head: Default::default(),
+            tail: Default::default(),
+            len: 0,
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    head: EntryPtr<T>,
+    tail: EntryPtr<T>,
+    left: usize,
+    axis: usize,
+    phantom: PhantomData<&'a T>,
+}
+
+pub struct SparseMatrix<T> {
+    lines: [Vec<Line<T>>; 2],
+    len: usize,
+}
+
+impl<T> SparseMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> SparseMatrix<T> {
+        SparseMatrix {
+            lines: [
+                core::iter::repeat_with(Default::default)
+                    .take(rows)
+                    .collect(),
+                core::iter::repeat_with(Default::default)
+                    .take(cols)
+                    .collect(),
+            ],
+            len: 0,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.lines[ROW].len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.lines[COL].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new(self.rows(), self.cols())
+    }
+
+    // last entry of the line whose index along the other axis is at most `at`
+    fn _find(&self, axis: usize, line: usize, at: usize) -> EntryPtr<T> {
+        let mut entry_ptr = self.lines[axis][line].tail;
+        while let Some(entry) = entry_ptr.as_ref() {
+            if entry.index[1 - axis] <= at {
+                break;
+            }
+            entry_ptr = entry.prev[axis];
+        }
+        entry_ptr
+    }
+
+    fn _find_exact(&self, row: usize, col: usize) -> EntryPtr<T> {
+        assert!(
+            row < self.rows() && col < self.cols(),
+            "Cannot access an index outside of the matrix bounds"
+        );
+        let (axis, line, at) = if self.lines[ROW][row].len <= self.lines[COL][col].len {
+            (ROW, row, col)
+        } else {
+            (COL, col, row)
+        };
+        let entry_ptr = self._find(axis, line, at);
+        match entry_ptr.as_ref() {
+            Some(entry) if entry.index[1 - axis] == at => entry_ptr,
+            _ => Default::default(),
+        }
+    }
+
+    fn _link_after(&mut self, axis: usize, mut before: EntryPtr<T>, inserted: EntryPtr<T>) {
+        let node = inserted.as_mut_unchecked();
+        let line = &mut self.lines[axis][node.index[axis]];
+        let mut after = match before.as_mut() {
+            Some(before_node) => core::mem::replace(&mut before_node.next[axis], inserted),
+            None => core::mem::replace(&mut line.head, inserted),
+        };
+        match after.as_mut() {
+            Some(after_node) => after_node.prev[axis] = inserted,
+            None => line.tail = inserted,
+        }
+        node.prev[axis] = before;
+        node.next[axis] = after;
+        line.len += 1;
+    }
+
+    fn _unlink(&mut self, axis: usize, removed: EntryPtr<T>) {
+        let node = removed.as_mut_unchecked();
+        let line = &mut self.lines[axis][node.index[axis]];
+        match node.prev[axis].as_mut() {
+            Some(before) => before.next[axis] = node.next[axis],
+            None => line.head = node.next[axis],
+        }
+        match node.next[axis].as_mut() {
+            Some(after) => after.prev[axis] = node.prev[axis],
+            None => line.tail = node.prev[axis],
+        }
+        line.len -= 1;
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self._find_exact(row, col)
+            .as_ref()
+            .map(|entry| &entry.value)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self._find_exact(row, col)
+            .as_mut()
+            .map(|entry| &mut entry.value)
+    }
+
+    pub fn insert(&mut self, row: usize, col: usize, value: T) -> Option<T> {
+        assert!(
+            row < self.rows() && col < self.cols(),
+            "Cannot insert at an index outside of the matrix bounds"
+        );
+        let mut before_in_row = self._find(ROW, row, col);
+        if let Some(entry) = before_in_row.as_mut() {
+            if entry.index[COL] == col {
+                return Some(core::mem::replace(&mut entry.value, value));
+            }
+        }
+        let before_in_col = self._find(COL, col, row);
+        let inserted = EntryPtr::from_box(Box::new(Entry {
+            value,
+            index: [row, col],
+            prev: Default::default(),
+            next: Default::default(),
+        }));
+        self._link_after(ROW, before_in_row, inserted);
+        self._link_after(COL, before_in_col, inserted);
+        self.len += 1;
+        None
+    }
+
+    pub fn remove(&mut self, row: usize, col: usize) -> Option<T> {
+        let removed = self._find_exact(row, col);
+        removed.ptr?;
+        self._unlink(ROW, removed);
+        self._unlink(COL, removed);
+        self.len -= 1;
+        Some(removed.into_box_unchecked().value)
+    }
+
+    fn _line(&self, axis: usize, at: usize) -> Iter<'_, T> {
+        let line = &self.lines[axis][at];
+        Iter {
+            head: line.head,
+            tail: line.tail,
+            left: line.len,
+            axis,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn row(&self, row: usize) -> Iter<'_, T> {
+        self._line(ROW, row)
+    }
+
+    pub fn col(&self, col: usize) -> Iter<'_, T> {
+        self._line(COL, col)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        (0..self.rows()).flat_map(move |row| self.row(row).map(move |(col, elt)| (row, col, elt)))
+    }
+
+    pub fn transpose(mut self) -> SparseMatrix<T> {
+        for row in 0..self.rows() {
+            let mut entry_ptr = self.lines[ROW][row].head;
+            while let Some(entry) = entry_ptr.as_mut() {
+                entry_ptr = entry.next[ROW];
+                entry.index.swap(ROW, COL);
+                entry.prev.swap(ROW, COL);
+                entry.next.swap(ROW, COL);
+            }
+        }
+        self.lines.swap(ROW, COL);
+        self
+    }
+}
+
+impl<T: Clone> Clone for SparseMatrix<T> {
+    fn clone(&self) -> Self {
+        let mut matrix = Self::new(self.rows(), self.cols());
+        for (row, col, elt) in self.iter() {
+            matrix.insert(row, col, elt.clone());
+        }
+        matrix
+    }
+}
+
+impl<T: Debug> Debug for SparseMatrix<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SparseMatrix")
+            .field("rows", &self.rows())
+            .field("cols", &self.cols())
+            .field(
+                "entries",
+                &self
+                    .iter()
+                    .map(|(row, col, elt)| ((row, col), elt))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<T> Drop for SparseMatrix<T> {
+    fn drop(&mut self) {
+        struct DropGuard<T>(EntryPtr<T>);
+        impl<T> Drop for DropGuard<T> {
+            fn drop(&mut self) {
+                while let Some(boxed) = self.0.into_box() {
+                    self.0 = boxed.next[ROW];
+                }
+            }
+        }
+        // chain the rows so the guard can free every remaining entry
+        let mut entry_ptr = EntryPtr::default();
+        for line in self.lines[ROW].iter().rev() {
+            let mut tail = line.tail;
+            if let Some(tail_entry) = tail.as_mut() {
+                tail_entry.next[ROW] = entry_ptr;
+                entry_ptr = line.head;
+            }
+        }
+        while let Some(boxed) = entry_ptr.into_box() {
+            entry_ptr = boxed.next[ROW];
+            let guard = DropGuard(entry_ptr);
+            drop(boxed); // can panic
+            core::mem::forget(guard);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows() == other.rows() && self.cols() == other.cols() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SparseMatrix<T> {}
+
+impl<T: Clone + Add<Output = T> + Mul<Output = T>> Mul for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.cols(),
+            rhs.rows(),
+            "Cannot multiply matrices with mismatched dimensions"
+        );
+        let mut product = SparseMatrix::new(self.rows(), rhs.cols());
+        let mut acc: Vec<Option<T>> = core::iter::repeat_with(|| None).take(rhs.cols()).collect();
+        let mut touched = Vec::new();
+        for row in 0..self.rows() {
+            for (k, a) in self.row(row) {
+                for (col, b) in rhs.row(k) {
+                    let term = a.clone() * b.clone();
+                    acc[col] = Some(match acc[col].take() {
+                        Some(sum) => sum + term,
+                        None => {
+                            touched.push(col);
+                            term
+                        }
+                    });
+                }
+            }
+            touched.sort_unstable();
+            for col in touched.drain(..) {
+                let sum = unsafe { acc[col].take().unwrap_unchecked() };
+                product.insert(row, col, sum);
+            }
+        }
+        product
+    }
+}
+
+unsafe impl<T: Send> Send for SparseMatrix<T> {}
+
+unsafe impl<T: Sync> Sync for SparseMatrix<T> {}
+
+// Iter
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Iter<'_, T> {}
+
+impl<T: Debug> Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+        let entry = self.tail.as_mut_unchecked();
+        self.tail = entry.prev[self.axis];
+        self.left -= 1;
+        Some((entry.index[1 - self.axis], &entry.value))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    fn len(&self) -> usize {
+        self.left
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+        let entry = self.head.as_mut_unchecked();
+        self.head = entry.next[self.axis];
+        self.left -= 1;
+        Some((entry.index[1 - self.axis], &entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+
+    fn last(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.next_back()
+    }
+}
+
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
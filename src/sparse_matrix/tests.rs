@@ -0,0 +1,160 @@
+use super::*;
+use crate::test_util::*;
+
+type Dense = Vec<Vec<Option<i64>>>;
+
+fn random_pair(seed: &mut u64, rows: usize, cols: usize) -> (SparseMatrix<i64>, Dense) {
+    let mut sparse = SparseMatrix::new(rows, cols);
+    let mut dense = vec![vec![None; cols]; rows];
+    for _ in 0..rows * cols / 3 {
+        let row = pseudo_random(seed) as usize % rows;
+        let col = pseudo_random(seed) as usize % cols;
+        let value = pseudo_random(seed) as i64 % 19 - 9;
+        assert_eq!(
+            sparse.insert(row, col, value),
+            dense[row][col].replace(value)
+        );
+    }
+    (sparse, dense)
+}
+
+fn assert_matches(sparse: &SparseMatrix<i64>, dense: &Dense) {
+    assert_eq!(sparse.rows(), dense.len());
+    let nonzeros = dense.iter().flatten().flatten().count();
+    assert_eq!(sparse.len(), nonzeros);
+    for (row, values) in dense.iter().enumerate() {
+        assert_eq!(sparse.cols(), values.len());
+        let expected: Vec<_> = values
+            .iter()
+            .enumerate()
+            .filter_map(|(col, value)| value.as_ref().map(|value| (col, value)))
+            .collect();
+        assert_eq!(sparse.row(row).collect::<Vec<_>>(), expected);
+        assert!(sparse.row(row).rev().eq(expected.into_iter().rev()));
+        for (col, value) in values.iter().enumerate() {
+            assert_eq!(sparse.get(row, col), value.as_ref());
+        }
+    }
+    for col in 0..sparse.cols() {
+        let expected: Vec<_> = dense
+            .iter()
+            .enumerate()
+            .filter_map(|(row, values)| values[col].as_ref().map(|value| (row, value)))
+            .collect();
+        assert_eq!(sparse.col(col).len(), expected.len());
+        assert_eq!(sparse.col(col).collect::<Vec<_>>(), expected);
+        assert!(sparse.col(col).rev().eq(expected.into_iter().rev()));
+    }
+}
+
+#[test]
+fn test_empty() {
+    let mut matrix = SparseMatrix::<i32>::new(3, 4);
+    assert!(matrix.is_empty());
+    assert_eq!(matrix.get(2, 3), None);
+    assert_eq!(matrix.remove(1, 1), None);
+    assert_eq!(matrix.row(0).count(), 0);
+    assert_eq!(matrix.col(3).count(), 0);
+    assert_eq!(matrix.iter().count(), 0);
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut matrix = SparseMatrix::new(3, 3);
+    assert_eq!(matrix.insert(1, 1, 5), None);
+    assert_eq!(matrix.insert(0, 1, 2), None);
+    assert_eq!(matrix.insert(1, 0, 3), None);
+    assert_eq!(matrix.insert(1, 1, 6), Some(5));
+    assert_eq!(matrix.len(), 3);
+    *matrix.get_mut(0, 1).unwrap() += 1;
+    assert_eq!(
+        matrix.iter().collect::<Vec<_>>(),
+        [(0, 1, &3), (1, 0, &3), (1, 1, &6)]
+    );
+    assert_eq!(matrix.remove(1, 1), Some(6));
+    assert_eq!(matrix.remove(1, 1), None);
+    assert_eq!(matrix.col(1).collect::<Vec<_>>(), [(0, &3)]);
+    matrix.clear();
+    assert!(matrix.is_empty());
+    assert_eq!(matrix.rows(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_out_of_bounds() {
+    SparseMatrix::new(2, 2).insert(0, 2, ());
+}
+
+#[test]
+fn test_against_dense() {
+    let mut seed = 1;
+    let (mut sparse, mut dense) = random_pair(&mut seed, 13, 7);
+    assert_matches(&sparse, &dense);
+    for _ in 0..60 {
+        let row = pseudo_random(&mut seed) as usize % 13;
+        let col = pseudo_random(&mut seed) as usize % 7;
+        assert_eq!(sparse.remove(row, col), dense[row][col].take());
+    }
+    assert_matches(&sparse, &dense);
+    assert_eq!(sparse.clone(), sparse);
+    assert_matches(&sparse.clone(), &dense);
+}
+
+#[test]
+fn test_transpose() {
+    let mut seed = 2;
+    let (sparse, dense) = random_pair(&mut seed, 6, 11);
+    let transposed: Dense = (0..11)
+        .map(|col| dense.iter().map(|values| values[col]).collect())
+        .collect();
+    let sparse = sparse.transpose();
+    assert_matches(&sparse, &transposed);
+    assert_matches(&sparse.transpose(), &dense);
+}
+
+#[test]
+fn test_mul() {
+    let mut seed = 3;
+    let (a, dense_a) = random_pair(&mut seed, 5, 8);
+    let (b, dense_b) = random_pair(&mut seed, 8, 4);
+    let mut product = vec![vec![None; 4]; 5];
+    for (i, values) in dense_a.iter().enumerate() {
+        for (k, a) in values.iter().enumerate() {
+            for (j, b) in dense_b[k].iter().enumerate() {
+                if let (Some(a), Some(b)) = (a, b) {
+                    product[i][j] = Some(product[i][j].unwrap_or(0) + a * b);
+                }
+            }
+        }
+    }
+    assert_matches(&(&a * &b), &product);
+}
+
+#[test]
+#[should_panic]
+fn test_mul_mismatched() {
+    let _ = &SparseMatrix::<i32>::new(2, 3) * &SparseMatrix::new(2, 3);
+}
+
+#[test]
+fn test_drop() {
+    static mut DROPS: i32 = 0;
+    struct Elem;
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            unsafe {
+                DROPS += 1;
+            }
+        }
+    }
+
+    let mut matrix = SparseMatrix::new(4, 4);
+    for i in 0..4 {
+        matrix.insert(i, 3 - i, Elem);
+        matrix.insert(i, i, Elem);
+    }
+    drop(matrix.remove(0, 0));
+    assert_eq!(unsafe { DROPS }, 1);
+    drop(matrix);
+    assert_eq!(unsafe { DROPS }, 8);
+}
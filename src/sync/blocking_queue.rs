@@ -0,0 +1,250 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::FusedIterator,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::linked_list::LinkedList;
+
+struct State<T> {
+    list: LinkedList<T>,
+    closed: bool,
+}
+
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Closed<T>(pub T);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TryPushError<T> {
+    Full(T),
+    Closed(T),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PopTimeoutError {
+    Timeout,
+    Closed,
+}
+
+pub struct Iter<'a, T> {
+    queue: &'a BlockingQueue<T>,
+}
+
+impl<T> BlockingQueue<T> {
+    pub fn new(capacity: usize) -> BlockingQueue<T> {
+        assert!(capacity > 0, "Cannot create a queue without capacity");
+        BlockingQueue {
+            state: Mutex::new(State {
+                list: LinkedList::new(),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    fn _lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self._lock().list.len()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self._lock().closed
+    }
+
+    pub fn close(&self) {
+        self._lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    pub fn push(&self, value: T) -> Result<(), Closed<T>> {
+        let mut state = self._lock();
+        while !state.closed && state.list.len() >= self.capacity {
+            state = self
+                .not_full
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        if state.closed {
+            return Err(Closed(value));
+        }
+        state.list.push_back(value);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    pub fn try_push(&self, value: T) -> Result<(), TryPushError<T>> {
+        let mut state = self._lock();
+        if state.closed {
+            return Err(TryPushError::Closed(value));
+        } else if state.list.len() >= self.capacity {
+            return Err(TryPushError::Full(value));
+        }
+        state.list.push_back(value);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    fn _pop_locked(&self, state: &mut State<T>) -> Option<T> {
+        let value = state.list.pop_front()?;
+        self.not_full.notify_one();
+        Some(value)
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self._lock();
+        while !state.closed && state.list.is_empty() {
+            state = self
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        self._pop_locked(&mut state)
+    }
+
+    pub fn try_pop(&self) -> Option<T> {
+        self._pop_locked(&mut self._lock())
+    }
+
+    pub fn pop_timeout(&self, timeout: Duration) -> Result<T, PopTimeoutError> {
+        let Some(deadline) = Instant::now().checked_add(timeout) else {
+            return self.pop().ok_or(PopTimeoutError::Closed);
+        };
+        let mut state = self._lock();
+        loop {
+            if let Some(value) = self._pop_locked(&mut state) {
+                return Ok(value);
+            } else if state.closed {
+                return Err(PopTimeoutError::Closed);
+            }
+            let Some(left) = deadline.checked_duration_since(Instant::now()) else {
+                return Err(PopTimeoutError::Timeout);
+            };
+            state = self
+                .not_empty
+                .wait_timeout(state, left)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    pub fn drain_all(&self) -> LinkedList<T> {
+        let drained = std::mem::take(&mut self._lock().list);
+        self.not_full.notify_all();
+        drained
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { queue: self }
+    }
+}
+
+impl<T: Debug> Debug for BlockingQueue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self._lock();
+        f.debug_struct("BlockingQueue")
+            .field("list", &state.list)
+            .field("capacity", &self.capacity)
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BlockingQueue<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Closed
+
+impl<T> Debug for Closed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Closed").finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for Closed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("pushing on a closed queue")
+    }
+}
+
+impl<T> std::error::Error for Closed<T> {}
+
+// TryPushError
+
+impl<T> Debug for TryPushError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryPushError::Full(_) => f.debug_tuple("Full").finish_non_exhaustive(),
+            TryPushError::Closed(_) => f.debug_tuple("Closed").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl<T> Display for TryPushError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryPushError::Full(_) => f.write_str("pushing on a full queue"),
+            TryPushError::Closed(_) => f.write_str("pushing on a closed queue"),
+        }
+    }
+}
+
+impl<T> std::error::Error for TryPushError<T> {}
+
+// PopTimeoutError
+
+impl Display for PopTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PopTimeoutError::Timeout => f.write_str("timed out popping from an empty queue"),
+            PopTimeoutError::Closed => f.write_str("popping from a closed and empty queue"),
+        }
+    }
+}
+
+impl std::error::Error for PopTimeoutError {}
+
+// Iter
+
+impl<T: Debug> Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Iter").field(&self.queue).finish()
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.queue.pop()
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+};
+
+use crate::linked_list::{LinkedList, NodePtr};
+
+use super::{Closed, TryPushError};
+
+struct Waiter {
+    waker: Waker,
+    linked: bool,
+}
+
+struct State<T> {
+    buffer: LinkedList<T>,
+    send_waiters: LinkedList<Waiter>,
+    recv_waiters: LinkedList<Waiter>,
+    senders: usize,
+    receivers: usize,
+    closed: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    capacity: usize,
+}
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    waiter: NodePtr<Waiter>,
+}
+
+pub struct SendFuture<'a, T> {
+    sender: &'a Sender<T>,
+    value: Option<T>,
+    waiter: NodePtr<Waiter>,
+}
+
+pub struct RecvFuture<'a, T> {
+    receiver: &'a mut Receiver<T>,
+}
+
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "Cannot create a channel without capacity");
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            buffer: LinkedList::new(),
+            send_waiters: LinkedList::new(),
+            recv_waiters: LinkedList::new(),
+            senders: 1,
+            receivers: 1,
+            closed: false,
+        }),
+        capacity,
+    });
+    (
+        Sender {
+            shared: Arc::clone(&shared),
+        },
+        Receiver {
+            shared,
+            waiter: Default::default(),
+        },
+    )
+}
+
+// a notified waiter is unlinked but stays allocated until its owner releases it
+fn notify_one(waiters: &mut LinkedList<Waiter>) -> Option<Waker> {
+    let node = waiters.head;
+    node.ptr?;
+    let list = waiters._unlink_as_list(node);
+    std::mem::forget(list);
+    let waiter = &mut node.as_mut_unchecked().value;
+    waiter.linked = false;
+    Some(waiter.waker.clone())
+}
+
+fn wake(waker: Option<Waker>) {
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+fn notify_all(waiters: &mut LinkedList<Waiter>) -> Vec<Waker> {
+    std::iter::from_fn(|| notify_one(waiters)).collect()
+}
+
+fn register(waiters: &mut LinkedList<Waiter>, waker: &Waker) -> NodePtr<Waiter> {
+    waiters.push_back(Waiter {
+        waker: waker.clone(),
+        linked: true,
+    });
+    waiters.tail
+}
+
+// returns whether the waiter had been notified
+fn release(waiters: &mut LinkedList<Waiter>, waiter: &mut NodePtr<Waiter>) -> bool {
+    let node = std::mem::take(waiter);
+    let Some(entry) = node.as_ref() else {
+        return false;
+    };
+    let notified = !entry.value.linked;
+    if notified {
+        drop(node.into_box_unchecked());
+    } else {
+        drop(waiters._unlink_as_list(node));
+    }
+    notified
+}
+
+impl<T> Shared<T> {
+    fn _lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn _close(&self) {
+        let wakers = {
+            let mut state = self._lock();
+            state.closed = true;
+            let mut wakers = notify_all(&mut state.send_waiters);
+            wakers.extend(notify_all(&mut state.recv_waiters));
+            wakers
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    fn _try_send(&self, state: &mut State<T>, value: T) -> Result<Option<Waker>, TryPushError<T>> {
+        if state.closed || state.receivers == 0 {
+            return Err(TryPushError::Closed(value));
+        } else if state.buffer.len() >= self.capacity {
+            return Err(TryPushError::Full(value));
+        }
+        state.buffer.push_back(value);
+        Ok(notify_one(&mut state.recv_waiters))
+    }
+}
+
+// Sender
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> SendFuture<'_, T> {
+        SendFuture {
+            sender: self,
+            value: Some(value),
+            waiter: Default::default(),
+        }
+    }
+
+    pub fn try_send(&self, value: T) -> Result<(), TryPushError<T>> {
+        let waker = self.shared._try_send(&mut self.shared._lock(), value)?;
+        wake(waker);
+        Ok(())
+    }
+
+    pub fn close(&self) {
+        self.shared._close();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        let state = self.shared._lock();
+        state.closed || state.receivers == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared._lock().senders += 1;
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sender")
+            .field("is_closed", &self.is_closed())
+            .finish()
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let last = {
+            let mut state = self.shared._lock();
+            state.senders -= 1;
+            state.senders == 0
+        };
+        if last {
+            self.shared._close();
+        }
+    }
+}
+
+// Receiver
+
+impl<T> Receiver<T> {
+    pub fn recv(&mut self) -> RecvFuture<'_, T> {
+        RecvFuture { receiver: self }
+    }
+
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.shared._lock();
+        release(&mut state.recv_waiters, &mut self.waiter);
+        if let Some(value) = state.buffer.pop_front() {
+            let waker = notify_one(&mut state.send_waiters);
+            drop(state);
+            wake(waker);
+            Poll::Ready(Some(value))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            self.waiter = register(&mut state.recv_waiters, cx.waker());
+            Poll::Pending
+        }
+    }
+
+    pub fn try_recv(&mut self) -> Option<T> {
+        let (value, waker) = {
+            let mut state = self.shared._lock();
+            let value = state.buffer.pop_front()?;
+            (value, notify_one(&mut state.send_waiters))
+        };
+        wake(waker);
+        Some(value)
+    }
+
+    pub fn close(&self) {
+        self.shared._close();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.shared._lock().closed
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared._lock().buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared._lock().receivers += 1;
+        Receiver {
+            shared: Arc::clone(&self.shared),
+            waiter: Default::default(),
+        }
+    }
+}
+
+impl<T> Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Receiver")
+            .field("len", &self.len())
+            .field("is_closed", &self.is_closed())
+            .finish()
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let (last, waker) = {
+            let mut state = self.shared._lock();
+            state.receivers -= 1;
+            let waker = release(&mut state.recv_waiters, &mut self.waiter)
+                .then(|| notify_one(&mut state.recv_waiters))
+                .flatten();
+            (state.receivers == 0, waker)
+        };
+        wake(waker);
+        if last {
+            self.shared._close();
+        }
+    }
+}
+
+// SendFuture
+
+impl<T> Debug for SendFuture<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SendFuture")
+            .field("sender", &self.sender)
+            .field("is_done", &self.value.is_none())
+            .finish()
+    }
+}
+
+impl<T> Drop for SendFuture<'_, T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.sender.shared._lock();
+            release(&mut state.send_waiters, &mut self.waiter)
+                .then(|| notify_one(&mut state.send_waiters))
+                .flatten()
+        };
+        wake(waker);
+    }
+}
+
+impl<T> Future for SendFuture<'_, T> {
+    type Output = Result<(), Closed<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let value = this.value.take().expect("Cannot poll a completed future");
+        let shared = &this.sender.shared;
+        let mut state = shared._lock();
+        release(&mut state.send_waiters, &mut this.waiter);
+        match shared._try_send(&mut state, value) {
+            Ok(waker) => {
+                drop(state);
+                wake(waker);
+                Poll::Ready(Ok(()))
+            }
+            Err(TryPushError::Closed(value)) => Poll::Ready(Err(Closed(value))),
+            Err(TryPushError::Full(value)) => {
+                this.value = Some(value);
+                this.waiter = register(&mut state.send_waiters, cx.waker());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// RecvFuture
+
+impl<T> Debug for RecvFuture<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RecvFuture").field(&self.receiver).finish()
+    }
+}
+
+impl<T> Drop for RecvFuture<'_, T> {
+    fn drop(&mut self) {
+        let receiver = &mut *self.receiver;
+        let waker = {
+            let mut state = receiver.shared._lock();
+            release(&mut state.recv_waiters, &mut receiver.waiter)
+                .then(|| notify_one(&mut state.recv_waiters))
+                .flatten()
+        };
+        wake(waker);
+    }
+}
+
+impl<T> Future for RecvFuture<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
+
+impl<T> Unpin for SendFuture<'_, T> {}
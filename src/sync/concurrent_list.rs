@@ -0,0 +1,292 @@
+use std::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    ptr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+};
+
+use crate::linked_list::LinkedList;
+
+// every lock is taken while holding the lock of the predecessor, so a node
+// that is unlinked under both locks can no longer be reached nor waited on
+struct Node<T> {
+    lock: Mutex<()>,
+    value: UnsafeCell<Option<T>>,
+    next: UnsafeCell<*mut Node<T>>,
+}
+
+struct Locked<'a, T> {
+    node: &'a Node<T>,
+    _guard: MutexGuard<'a, ()>,
+}
+
+pub struct ConcurrentList<T> {
+    head: Box<Node<T>>,
+    len: AtomicUsize,
+}
+
+pub struct ListCursor<'a, T> {
+    list: &'a ConcurrentList<T>,
+    prev: Locked<'a, T>,
+    current: Option<Locked<'a, T>>,
+}
+
+impl<T> Node<T> {
+    fn new(value: Option<T>, next: *mut Node<T>) -> Node<T> {
+        Node {
+            lock: Mutex::new(()),
+            value: UnsafeCell::new(value),
+            next: UnsafeCell::new(next),
+        }
+    }
+
+    fn lock(&self) -> Locked<'_, T> {
+        Locked {
+            node: self,
+            _guard: self.lock.lock().unwrap_or_else(PoisonError::into_inner),
+        }
+    }
+}
+
+impl<T> Locked<'_, T> {
+    fn as_ptr(&self) -> *mut Node<T> {
+        self.node as *const Node<T> as *mut Node<T>
+    }
+
+    fn next(&self) -> *mut Node<T> {
+        unsafe { *self.node.next.get() }
+    }
+
+    fn set_next(&self, next: *mut Node<T>) {
+        unsafe { *self.node.next.get() = next };
+    }
+}
+
+unsafe fn _lock_ptr<'a, T>(ptr: *mut Node<T>) -> Option<Locked<'a, T>> {
+    ptr.as_ref().map(Node::lock)
+}
+
+impl<T> ConcurrentList<T> {
+    pub fn new() -> ConcurrentList<T> {
+        ConcurrentList {
+            head: Box::new(Node::new(None, ptr::null_mut())),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    pub fn cursor(&self) -> ListCursor<'_, T> {
+        let prev = self.head.lock();
+        let current = unsafe { _lock_ptr(prev.next()) };
+        ListCursor {
+            list: self,
+            prev,
+            current,
+        }
+    }
+
+    pub fn push_front(&self, value: T) {
+        let head = self.head.lock();
+        let node = Box::into_raw(Box::new(Node::new(Some(value), head.next())));
+        head.set_next(node);
+        self.len.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn push_back(&self, value: T) {
+        let mut cursor = self.cursor();
+        while cursor.move_next() {}
+        cursor.insert_before(value);
+    }
+
+    pub fn pop_front(&self) -> Option<T> {
+        self.cursor().remove_current()
+    }
+
+    pub fn remove_first<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<T> {
+        let mut cursor = self.cursor();
+        while let Some(value) = cursor.current() {
+            if pred(value) {
+                return cursor.remove_current();
+            }
+            cursor.move_next();
+        }
+        None
+    }
+
+    pub fn retain<F: FnMut(&mut T) -> bool>(&self, mut f: F) {
+        let mut cursor = self.cursor();
+        while let Some(value) = cursor.current_mut() {
+            if f(value) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
+            }
+        }
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut cursor = self.cursor();
+        while let Some(value) = cursor.current() {
+            if value == x {
+                return true;
+            }
+            cursor.move_next();
+        }
+        false
+    }
+
+    pub fn for_each<F: FnMut(&T)>(&self, mut f: F) {
+        let mut cursor = self.cursor();
+        while let Some(value) = cursor.current() {
+            f(value);
+            cursor.move_next();
+        }
+    }
+
+    fn _take_all(&mut self) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        let mut node = std::mem::replace(self.head.next.get_mut(), ptr::null_mut());
+        while !node.is_null() {
+            let boxed = unsafe { Box::from_raw(node) };
+            node = unsafe { *boxed.next.get() };
+            list.extend(boxed.value.into_inner());
+        }
+        *self.len.get_mut() = 0;
+        list
+    }
+
+    pub fn into_list(mut self) -> LinkedList<T> {
+        self._take_all()
+    }
+}
+
+impl<T: Debug> Debug for ConcurrentList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut list = f.debug_list();
+        self.for_each(|value| {
+            list.entry(value);
+        });
+        list.finish()
+    }
+}
+
+impl<T> Default for ConcurrentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for ConcurrentList<T> {
+    fn drop(&mut self) {
+        self._take_all();
+    }
+}
+
+impl<T> Extend<T> for ConcurrentList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut cursor = self.cursor();
+        while cursor.move_next() {}
+        for value in iter {
+            cursor.insert_before(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ConcurrentList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> From<LinkedList<T>> for ConcurrentList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+unsafe impl<T: Send> Send for ConcurrentList<T> {}
+
+unsafe impl<T: Send> Sync for ConcurrentList<T> {}
+
+// ListCursor
+
+impl<T> ListCursor<'_, T> {
+    pub fn is_ghost(&self) -> bool {
+        self.current.is_none()
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        let current = self.current.as_ref()?;
+        unsafe { (*current.node.value.get()).as_ref() }
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let current = self.current.as_ref()?;
+        unsafe { (*current.node.value.get()).as_mut() }
+    }
+
+    pub fn move_next(&mut self) -> bool {
+        let Some(current) = self.current.take() else {
+            return false;
+        };
+        self.current = unsafe { _lock_ptr(current.next()) };
+        self.prev = current;
+        self.current.is_some()
+    }
+
+    // on the ghost position the value is appended and the cursor stays on the ghost
+    pub fn insert_after(&mut self, value: T) {
+        match &self.current {
+            Some(current) => {
+                let node = Box::into_raw(Box::new(Node::new(Some(value), current.next())));
+                current.set_next(node);
+                self.list.len.fetch_add(1, Ordering::Relaxed);
+            }
+            None => self.insert_before(value),
+        }
+    }
+
+    pub fn insert_before(&mut self, value: T) {
+        let next = self
+            .current
+            .as_ref()
+            .map_or(ptr::null_mut(), Locked::as_ptr);
+        let node = Box::into_raw(Box::new(Node::new(Some(value), next)));
+        self.prev.set_next(node);
+        self.prev = unsafe { (*node).lock() };
+        self.list.len.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current.take()?;
+        let next = current.next();
+        let node = current.as_ptr();
+        self.prev.set_next(next);
+        drop(current);
+        let node = unsafe { Box::from_raw(node) };
+        self.list.len.fetch_sub(1, Ordering::Relaxed);
+        self.current = unsafe { _lock_ptr(next) };
+        node.value.into_inner()
+    }
+}
+
+impl<T: Debug> Debug for ListCursor<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ListCursor").field(&self.current()).finish()
+    }
+}
@@ -0,0 +1,10 @@
+mod blocking_queue;
+mod channel;
+mod concurrent_list;
+
+#[cfg(test)]
+mod tests;
+
+pub use blocking_queue::{BlockingQueue, Closed, Iter, PopTimeoutError, TryPushError};
+pub use channel::{channel, Receiver, RecvFuture, SendFuture, Sender};
+pub use concurrent_list::{ConcurrentList, ListCursor};
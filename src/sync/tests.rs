@@ -0,0 +1,445 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::Future,
+    pin::{pin, Pin},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+use super::*;
+use crate::test_util::*;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+struct TaskWaker {
+    id: usize,
+    ready: Arc<Mutex<VecDeque<usize>>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.ready.lock().unwrap().push_back(self.id);
+    }
+}
+
+// runs every task on the current thread, panics if they deadlock
+fn run_all(tasks: Vec<Pin<Box<dyn Future<Output = ()> + '_>>>) {
+    let ready = Arc::new(Mutex::new((0..tasks.len()).collect::<VecDeque<_>>()));
+    let mut tasks: Vec<_> = tasks.into_iter().map(Some).collect();
+    while tasks.iter().any(Option::is_some) {
+        let id = ready
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("tasks are deadlocked");
+        let Some(task) = &mut tasks[id] else {
+            continue;
+        };
+        let waker = Waker::from(Arc::new(TaskWaker {
+            id,
+            ready: Arc::clone(&ready),
+        }));
+        if task
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_ready()
+        {
+            tasks[id] = None;
+        }
+    }
+}
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_push_pop() {
+    let queue = BlockingQueue::new(3);
+    assert!(queue.is_empty());
+    assert_eq!(queue.try_pop(), None);
+    assert_eq!(queue.push(1), Ok(()));
+    assert_eq!(queue.try_push(2), Ok(()));
+    assert_eq!(queue.try_push(3), Ok(()));
+    assert_eq!(queue.try_push(4), Err(TryPushError::Full(4)));
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.try_pop(), Some(2));
+    assert_eq!(queue.pop_timeout(Duration::ZERO), Ok(3));
+    assert_eq!(
+        queue.pop_timeout(Duration::from_millis(10)),
+        Err(PopTimeoutError::Timeout)
+    );
+}
+
+#[test]
+fn test_close() {
+    let queue = BlockingQueue::new(2);
+    queue.push('a').unwrap();
+    queue.close();
+    assert!(queue.is_closed());
+    assert_eq!(queue.push('b'), Err(Closed('b')));
+    assert_eq!(queue.try_push('c'), Err(TryPushError::Closed('c')));
+    assert_eq!(queue.pop(), Some('a'));
+    assert_eq!(queue.pop(), None);
+    assert_eq!(
+        queue.pop_timeout(Duration::from_secs(60)),
+        Err(PopTimeoutError::Closed)
+    );
+}
+
+#[test]
+fn test_drain_all() {
+    let queue = BlockingQueue::new(4);
+    for i in 0..4 {
+        queue.push(i).unwrap();
+    }
+    assert_eq!(queue.drain_all(), (0..4).collect());
+    assert!(queue.is_empty());
+    assert!(queue.drain_all().is_empty());
+}
+
+#[test]
+fn test_push_blocks_when_full() {
+    let queue = Arc::new(BlockingQueue::new(1));
+    queue.push(0).unwrap();
+    let producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            queue.push(1).unwrap();
+            queue.push(2).unwrap();
+        })
+    };
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.pop(), Some(0));
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.pop(), Some(2));
+    producer.join().unwrap();
+}
+
+#[test]
+fn test_close_wakes_blocked() {
+    let queue = Arc::new(BlockingQueue::<i32>::new(1));
+    let consumer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.pop())
+    };
+    thread::sleep(Duration::from_millis(20));
+    queue.close();
+    assert_eq!(consumer.join().unwrap(), None);
+
+    let queue = Arc::new(BlockingQueue::new(1));
+    queue.push(8).unwrap();
+    let producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.push(9))
+    };
+    thread::sleep(Duration::from_millis(20));
+    queue.close();
+    assert_eq!(producer.join().unwrap(), Err(Closed(9)));
+    assert_eq!(queue.pop(), Some(8));
+}
+
+#[test]
+fn test_worker_pool() {
+    const ITEMS: usize = 10_000;
+    let queue = Arc::new(BlockingQueue::new(16));
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                let mut received = Vec::new();
+                for item in queue.iter() {
+                    assert!(queue.len() <= queue.capacity());
+                    received.push(item);
+                }
+                received
+            })
+        })
+        .collect();
+    let producers: Vec<_> = (0..2)
+        .map(|p| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in (p..ITEMS).step_by(2) {
+                    queue.push(i).unwrap();
+                }
+            })
+        })
+        .collect();
+    producers
+        .into_iter()
+        .for_each(|producer| producer.join().unwrap());
+    queue.close();
+    let mut all: Vec<_> = workers
+        .into_iter()
+        .flat_map(|worker| worker.join().unwrap())
+        .collect();
+    all.sort_unstable();
+    assert_eq!(all, (0..ITEMS).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_channel_send_recv() {
+    let (sender, mut receiver) = channel(4);
+    block_on(async {
+        sender.send(1).await.unwrap();
+        sender.send(2).await.unwrap();
+        assert_eq!(receiver.len(), 2);
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.try_recv(), Some(2));
+        assert_eq!(receiver.try_recv(), None);
+    });
+    sender.try_send(3).unwrap();
+    drop(sender);
+    assert_eq!(block_on(receiver.recv()), Some(3));
+    assert_eq!(block_on(receiver.recv()), None);
+    assert!(receiver.is_closed());
+}
+
+#[test]
+fn test_channel_closed() {
+    let (sender, receiver) = channel(1);
+    let other = sender.clone();
+    drop(receiver);
+    assert!(sender.is_closed());
+    assert_eq!(block_on(sender.send('a')), Err(Closed('a')));
+    assert_eq!(other.try_send('b'), Err(TryPushError::Closed('b')));
+
+    let (sender, mut receiver) = channel(1);
+    sender.try_send(1).unwrap();
+    assert_eq!(sender.try_send(2), Err(TryPushError::Full(2)));
+    receiver.close();
+    assert_eq!(sender.try_send(3), Err(TryPushError::Closed(3)));
+    assert_eq!(receiver.try_recv(), Some(1));
+    assert_eq!(block_on(receiver.recv()), None);
+}
+
+#[test]
+fn test_channel_backpressure() {
+    let (sender, mut receiver) = channel(2);
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let max_len = Rc::new(RefCell::new(0));
+    let producer = Box::pin(async move {
+        for i in 0..100 {
+            sender.send(i).await.unwrap();
+        }
+    });
+    let consumer = {
+        let received = Rc::clone(&received);
+        let max_len = Rc::clone(&max_len);
+        Box::pin(async move {
+            while let Some(item) = receiver.recv().await {
+                let len = receiver.len();
+                max_len.replace_with(|&mut max| max.max(len));
+                received.borrow_mut().push(item);
+            }
+        })
+    };
+    run_all(vec![producer, consumer]);
+    assert_eq!(*received.borrow(), (0..100).collect::<Vec<_>>());
+    assert!(*max_len.borrow() <= 2);
+}
+
+#[test]
+fn test_channel_poll_recv() {
+    let (sender, mut receiver) = channel(1);
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(Arc::clone(&counter));
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(receiver.poll_recv(&mut cx), Poll::Pending);
+    assert_eq!(receiver.poll_recv(&mut cx), Poll::Pending);
+    sender.try_send(5).unwrap();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    assert_eq!(receiver.poll_recv(&mut cx), Poll::Ready(Some(5)));
+    assert_eq!(receiver.poll_recv(&mut cx), Poll::Pending);
+    drop(sender);
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+    assert_eq!(receiver.poll_recv(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn test_channel_cancelled_recv_passes_wakeup_on() {
+    let (sender, mut first) = channel(1);
+    let mut second = first.clone();
+    let first_counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let second_counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let first_waker = Waker::from(Arc::clone(&first_counter));
+    let second_waker = Waker::from(Arc::clone(&second_counter));
+    let mut first_recv = first.recv();
+    let mut second_recv = second.recv();
+    assert!(Pin::new(&mut first_recv)
+        .poll(&mut Context::from_waker(&first_waker))
+        .is_pending());
+    assert!(Pin::new(&mut second_recv)
+        .poll(&mut Context::from_waker(&second_waker))
+        .is_pending());
+    sender.try_send(1).unwrap();
+    assert_eq!(first_counter.0.load(Ordering::SeqCst), 1);
+    assert_eq!(second_counter.0.load(Ordering::SeqCst), 0);
+    drop(first_recv);
+    assert_eq!(second_counter.0.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        Pin::new(&mut second_recv).poll(&mut Context::from_waker(&second_waker)),
+        Poll::Ready(Some(1))
+    );
+}
+
+#[test]
+fn test_channel_threads() {
+    const ITEMS: usize = 5000;
+    let (sender, receiver) = channel(8);
+    let consumers: Vec<_> = (0..3)
+        .map(|_| {
+            let mut receiver = receiver.clone();
+            thread::spawn(move || {
+                block_on(async {
+                    let mut received = Vec::new();
+                    while let Some(item) = receiver.recv().await {
+                        received.push(item);
+                    }
+                    received
+                })
+            })
+        })
+        .collect();
+    drop(receiver);
+    let producers: Vec<_> = (0..3)
+        .map(|p| {
+            let sender = sender.clone();
+            thread::spawn(move || {
+                block_on(async {
+                    for i in (p..ITEMS).step_by(3) {
+                        sender.send(i).await.unwrap();
+                    }
+                })
+            })
+        })
+        .collect();
+    drop(sender);
+    producers
+        .into_iter()
+        .for_each(|producer| producer.join().unwrap());
+    let mut all: Vec<_> = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect();
+    all.sort_unstable();
+    assert_eq!(all, (0..ITEMS).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_concurrent_list_cursor() {
+    let list: ConcurrentList<_> = (1..=5).collect();
+    assert_eq!(list.len(), 5);
+    {
+        let mut cursor = list.cursor();
+        assert_eq!(cursor.current(), Some(&1));
+        cursor.insert_before(0);
+        assert!(cursor.move_next());
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&3));
+        cursor.insert_after(10);
+        *cursor.current_mut().unwrap() *= 2;
+        while cursor.move_next() {}
+        assert!(cursor.is_ghost());
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after(6);
+        cursor.insert_before(7);
+        assert!(cursor.is_ghost());
+    }
+    assert_eq!(format!("{list:?}"), "[0, 1, 6, 10, 4, 5, 6, 7]");
+    assert_eq!(list.len(), 8);
+    assert!(list.contains(&10));
+    assert_eq!(list.remove_first(|&x| x > 5), Some(6));
+    list.retain(|x| *x % 2 == 0);
+    list.push_front(-2);
+    list.push_back(8);
+    assert_eq!(list.pop_front(), Some(-2));
+    assert_eq!(list.len(), 5);
+    assert_eq!(
+        list.into_list(),
+        crate::linked_list::LinkedList::from([0, 10, 4, 6, 8])
+    );
+}
+
+#[test]
+fn test_concurrent_list_threads() {
+    const THREADS: usize = 4;
+    const PER_THREAD: usize = 1000;
+    // one marker per thread splits the list into regions edited independently
+    let list: ConcurrentList<_> = (0..THREADS).map(|t| (t, None)).collect();
+    thread::scope(|scope| {
+        for t in 0..THREADS {
+            let list = &list;
+            scope.spawn(move || {
+                let mut cursor = list.cursor();
+                while cursor.current().unwrap().0 != t {
+                    cursor.move_next();
+                }
+                for i in 0..PER_THREAD {
+                    cursor.insert_after((t, Some(i)));
+                }
+                cursor.move_next();
+                while let Some(&(owner, Some(i))) = cursor.current() {
+                    assert_eq!(owner, t);
+                    if i % 2 == 1 {
+                        cursor.remove_current();
+                    } else {
+                        cursor.move_next();
+                    }
+                }
+            });
+        }
+        scope.spawn(|| {
+            for _ in 0..100 {
+                list.push_back((THREADS, None));
+                assert_eq!(list.remove_first(|x| x.0 == THREADS), Some((THREADS, None)));
+            }
+        });
+    });
+    assert_eq!(list.len(), THREADS * (PER_THREAD / 2 + 1));
+    let mut items = Vec::new();
+    list.for_each(|x| items.push(*x));
+    let expected: Vec<_> = (0..THREADS)
+        .flat_map(|t| {
+            std::iter::once((t, None)).chain(
+                (0..PER_THREAD)
+                    .rev()
+                    .step_by(2)
+                    .map(move |i| (t, Some(i - 1))),
+            )
+        })
+        .collect();
+    assert_eq!(items, expected);
+}
@@ -0,0 +1,11 @@
+// the crate stays no_std in tests without the `std` feature, so the test
+// modules pull the alloc types the std prelude would otherwise provide
+pub(crate) use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+// deterministic LCG so randomized tests reproduce without a rand dependency
+pub(crate) fn pseudo_random(seed: &mut u64) -> u64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *seed >> 33
+}
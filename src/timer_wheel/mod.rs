@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, iter::FusedIterator};
+
+use crate::linked_list::{LinkedList, NodePtr};
+
+#[cfg(test)]
+mod tests;
+
+const SLOT_BITS: u32 = 6;
+const SLOTS: usize = 1 << SLOT_BITS;
+const SLOT_MASK: u64 = SLOTS as u64 - 1;
+const LEVELS: usize = 6;
+const OVERFLOW: usize = LEVELS * SLOTS;
+const EXPIRED: usize = OVERFLOW + 1;
+
+struct Timer<T> {
+    deadline: u64,
+    value: T,
+    list: usize,
+    key: usize,
+}
+
+struct Slot<T> {
+    generation: u64,
+    node: NodePtr<Timer<T>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerHandle {
+    key: usize,
+    generation: u64,
+}
+
+// lists holds LEVELS levels of SLOTS slots each, followed by the overflow and expired lists
+pub struct TimerWheel<T> {
+    lists: Vec<LinkedList<Timer<T>>>,
+    counts: [usize; LEVELS + 1],
+    slab: Vec<Slot<T>>,
+    vacant: Vec<usize>,
+    elapsed: u64,
+    len: usize,
+}
+
+#[must_use = "expired timers are only returned by consuming the iterator"]
+pub struct Expired<'a, T> {
+    wheel: &'a mut TimerWheel<T>,
+    now: u64,
+}
+
+impl<T> TimerWheel<T> {
+    pub fn new() -> TimerWheel<T> {
+        Self::with_start(0)
+    }
+
+    pub fn with_start(tick: u64) -> TimerWheel<T> {
+        TimerWheel {
+            lists: core::iter::repeat_with(LinkedList::new)
+                .take(EXPIRED + 1)
+                .collect(),
+            counts: [0; LEVELS + 1],
+            slab: Vec::new(),
+            vacant: Vec::new(),
+            elapsed: tick,
+            len: 0,
+        }
+    }
+
+    pub fn now(&self) -> u64 {
+        self.elapsed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    fn _node(&self, handle: TimerHandle) -> Option<NodePtr<Timer<T>>> {
+        self.slab
+            .get(handle.key)
+            .filter(|slot| slot.generation == handle.generation && slot.node.ptr.is_some())
+            .map(|slot| slot.node)
+    }
+
+    pub fn contains(&self, handle: TimerHandle) -> bool {
+        self._node(handle).is_some()
+    }
+
+    pub fn deadline(&self, handle: TimerHandle) -> Option<u64> {
+        self._node(handle)
+            .map(|node| node.as_mut_unchecked().value.deadline)
+    }
+
+    pub fn get(&self, handle: TimerHandle) -> Option<&T> {
+        self._node(handle)
+            .map(|node| &node.as_mut_unchecked().value.value)
+    }
+
+    pub fn get_mut(&mut self, handle: TimerHandle) -> Option<&mut T> {
+        self._node(handle)
+            .map(|node| &mut node.as_mut_unchecked().value.value)
+    }
+
+    fn _list_for(&self, deadline: u64) -> usize {
+        if deadline <= self.elapsed {
+            return EXPIRED;
+        }
+        let masked = (deadline ^ self.elapsed) | SLOT_MASK;
+        let level = ((u64::BITS - 1 - masked.leading_zeros()) / SLOT_BITS) as usize;
+        if level >= LEVELS {
+            return OVERFLOW;
+        }
+        level * SLOTS + ((deadline >> (level as u32 * SLOT_BITS)) & SLOT_MASK) as usize
+    }
+
+    fn _place(&mut self, mut timer: LinkedList<Timer<T>>) {
+        let entry = &mut timer.head.as_mut_unchecked().value;
+        entry.list = self._list_for(entry.deadline);
+        if entry.list != EXPIRED {
+            self.counts[entry.list / SLOTS] += 1;
+        }
+        self.lists[entry.list].append(&mut timer);
+    }
+
+    fn _release(&mut self, timer: Timer<T>) -> (u64, T) {
+        let slot = &mut self.slab[timer.key];
+        slot.node = Default::default();
+        slot.generation += 1;
+        self.vacant.push(timer.key);
+        self.len -= 1;
+        (timer.deadline, timer.value)
+    }
+
+    pub fn schedule(&mut self, deadline: u64, value: T) -> TimerHandle {
+        let key = self.vacant.pop().unwrap_or_else(|| {
+            self.slab.push(Slot {
+                generation: 0,
+                node: Default::default(),
+            });
+            self.slab.len() - 1
+        });
+        let timer = LinkedList::from([Timer {
+            deadline,
+            value,
+            list: EXPIRED,
+            key,
+        }]);
+        self.slab[key].node = timer.head;
+        self.len += 1;
+        self._place(timer);
+        TimerHandle {
+            key,
+            generation: self.slab[key].generation,
+        }
+    }
+
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<T> {
+        let node = self._node(handle)?;
+        let list = node.as_mut_unchecked().value.list;
+        if list != EXPIRED {
+            self.counts[list / SLOTS] -= 1;
+        }
+        let timer = self.lists[list]._unlink_as_list(node).pop_front()?;
+        Some(self._release(timer).1)
+    }
+
+    pub fn advance(&mut self, now: u64) -> Expired<'_, T> {
+        Expired { wheel: self, now }
+    }
+
+    fn _cascade(&mut self, list: usize) {
+        let mut timers = core::mem::take(&mut self.lists[list]);
+        self.counts[list / SLOTS] -= timers.len();
+        while let Some(timer) = timers.cursor_front_mut().remove_current_as_list() {
+            self._place(timer);
+        }
+    }
+
+    // moves time forward to the next tick that can expire or cascade timers
+    fn _step(&mut self, now: u64) -> bool {
+        if self.elapsed >= now {
+            return false;
+        }
+        self.elapsed = match self.counts.iter().position(|&count| count > 0) {
+            None => now,
+            Some(0) => self.elapsed + 1,
+            Some(level) => {
+                let shift = level as u32 * SLOT_BITS;
+                ((self.elapsed >> shift) + 1)
+                    .checked_mul(1 << shift)
+                    .map_or(now, |boundary| now.min(boundary))
+            }
+        };
+        let tick = self.elapsed;
+        if tick & ((1 << (LEVELS as u32 * SLOT_BITS)) - 1) == 0 {
+            self._cascade(OVERFLOW);
+        }
+        for level in (1..LEVELS).rev() {
+            let shift = level as u32 * SLOT_BITS;
+            if tick & ((1 << shift) - 1) == 0 {
+                self._cascade(level * SLOTS + ((tick >> shift) & SLOT_MASK) as usize);
+            }
+        }
+        let mut due = core::mem::take(&mut self.lists[(tick & SLOT_MASK) as usize]);
+        self.counts[0] -= due.len();
+        due.iter_mut().for_each(|timer| timer.list = EXPIRED);
+        self.lists[EXPIRED].append(&mut due);
+        true
+    }
+}
+
+impl<T: Debug> Debug for TimerWheel<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TimerWheel")
+            .field("now", &self.elapsed)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T> Default for TimerWheel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Expired
+
+impl<T: Debug> Debug for Expired<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Expired")
+            .field(&self.wheel)
+            .field(&self.now)
+            .finish()
+    }
+}
+
+// dropping the iterator still moves time to `now`; timers it did not yield
+// stay queued as expired and come out first on the next advance
+impl<T> Drop for Expired<'_, T> {
+    fn drop(&mut self) {
+        while self.wheel._step(self.now) {}
+    }
+}
+
+impl<T> FusedIterator for Expired<'_, T> {}
+
+impl<T> Iterator for Expired<'_, T> {
+    type Item = (u64, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(timer) = self.wheel.lists[EXPIRED].pop_front() {
+                return Some(self.wheel._release(timer));
+            }
+            if !self.wheel._step(self.now) {
+                return None;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.wheel.lists[EXPIRED].len(), Some(self.wheel.len()))
+    }
+}
@@ -0,0 +1,166 @@
+use super::*;
+use crate::test_util::*;
+
+#[test]
+fn test_empty() {
+    let mut wheel = TimerWheel::<()>::new();
+    assert!(wheel.is_empty());
+    assert_eq!(wheel.advance(1000).next(), None);
+    assert_eq!(wheel.now(), 1000);
+}
+
+#[test]
+fn test_advance_in_order() {
+    let mut wheel = TimerWheel::new();
+    for deadline in [5, 64, 3, 4096, 63, 65, 5, 1 << 20] {
+        wheel.schedule(deadline, deadline * 10);
+    }
+    assert_eq!(wheel.len(), 8);
+    assert_eq!(
+        wheel.advance(64).collect::<Vec<_>>(),
+        [(3, 30), (5, 50), (5, 50), (63, 630), (64, 640)]
+    );
+    assert_eq!(wheel.now(), 64);
+    assert_eq!(wheel.advance(64).next(), None);
+    assert_eq!(wheel.advance(4095).collect::<Vec<_>>(), [(65, 650)]);
+    assert_eq!(wheel.advance(4096).collect::<Vec<_>>(), [(4096, 40960)]);
+    assert_eq!(
+        wheel.advance(u64::MAX).collect::<Vec<_>>(),
+        [(1 << 20, 10 << 20)]
+    );
+    assert!(wheel.is_empty());
+}
+
+#[test]
+fn test_past_deadline() {
+    let mut wheel = TimerWheel::with_start(100);
+    wheel.schedule(50, 'a');
+    wheel.schedule(100, 'b');
+    wheel.schedule(101, 'c');
+    assert_eq!(
+        wheel.advance(100).collect::<Vec<_>>(),
+        [(50, 'a'), (100, 'b')]
+    );
+    assert_eq!(wheel.len(), 1);
+}
+
+#[test]
+fn test_end_of_time() {
+    let mut wheel = TimerWheel::with_start(u64::MAX - 10);
+    wheel.schedule(u64::MAX - 1, 1);
+    wheel.schedule(u64::MAX, 2);
+    assert_eq!(
+        wheel.advance(u64::MAX).collect::<Vec<_>>(),
+        [(u64::MAX - 1, 1), (u64::MAX, 2)]
+    );
+}
+
+#[test]
+fn test_cancel() {
+    let mut wheel = TimerWheel::new();
+    let a = wheel.schedule(10, 'a');
+    let b = wheel.schedule(1000, 'b');
+    let c = wheel.schedule(1 << 40, 'c');
+    assert_eq!(wheel.deadline(b), Some(1000));
+    assert_eq!(wheel.get(c), Some(&'c'));
+    assert_eq!(wheel.cancel(b), Some('b'));
+    assert_eq!(wheel.cancel(b), None);
+    assert!(!wheel.contains(b));
+    assert_eq!(wheel.advance(2000).collect::<Vec<_>>(), [(10, 'a')]);
+    assert_eq!(wheel.cancel(a), None);
+    let d = wheel.schedule(3000, 'd');
+    assert_ne!(a, d);
+    assert_eq!(wheel.get(a), None);
+    *wheel.get_mut(d).unwrap() = 'e';
+    assert_eq!(wheel.cancel(c), Some('c'));
+    assert_eq!(wheel.advance(1 << 41).collect::<Vec<_>>(), [(3000, 'e')]);
+    assert!(wheel.is_empty());
+}
+
+#[test]
+fn test_cancel_after_cascade() {
+    let mut wheel = TimerWheel::new();
+    let handle = wheel.schedule(5000, ());
+    assert_eq!(wheel.advance(4999).count(), 0);
+    assert_eq!(wheel.cancel(handle), Some(()));
+    assert_eq!(wheel.advance(6000).count(), 0);
+}
+
+#[test]
+fn test_partial_drain() {
+    let mut wheel = TimerWheel::new();
+    for deadline in 1..=10 {
+        wheel.schedule(deadline, deadline);
+    }
+    {
+        let mut expired = wheel.advance(10);
+        assert_eq!(expired.next(), Some((1, 1)));
+        assert_eq!(expired.next(), Some((2, 2)));
+    }
+    assert_eq!(wheel.len(), 8);
+    let rest: Vec<_> = wheel.advance(10).map(|(_, value)| value).collect();
+    assert_eq!(rest, (3..=10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_drop_unconsumed() {
+    let mut wheel = TimerWheel::new();
+    wheel.schedule(3, 'a');
+    wheel.schedule(7, 'b');
+    wheel.schedule(100, 'c');
+    drop(wheel.advance(10));
+    assert_eq!(wheel.now(), 10);
+    assert_eq!(wheel.len(), 3);
+    let handle = wheel.schedule(12, 'd');
+    assert_eq!(wheel.advance(10).collect::<Vec<_>>(), [(3, 'a'), (7, 'b')]);
+    assert_eq!(wheel.cancel(handle), Some('d'));
+    let _ = wheel.advance(200);
+    assert_eq!(wheel.now(), 200);
+    assert_eq!(wheel.advance(200).collect::<Vec<_>>(), [(100, 'c')]);
+}
+
+#[test]
+fn test_against_reference() {
+    let mut seed = 5;
+    let mut wheel = TimerWheel::new();
+    let mut pending: Vec<(u64, usize, TimerHandle)> = Vec::new();
+    let mut now = 0;
+    for id in 0..3000 {
+        match pseudo_random(&mut seed) % 5 {
+            0 | 1 => {
+                let delay = match pseudo_random(&mut seed) % 4 {
+                    0 => pseudo_random(&mut seed) % 64,
+                    1 => pseudo_random(&mut seed) % 5000,
+                    2 => pseudo_random(&mut seed) % (1 << 20),
+                    _ => pseudo_random(&mut seed) << 10,
+                };
+                let deadline = now + delay;
+                pending.push((deadline, id, wheel.schedule(deadline, id)));
+            }
+            2 if !pending.is_empty() => {
+                let at = pseudo_random(&mut seed) as usize % pending.len();
+                let (_, id, handle) = pending.swap_remove(at);
+                assert_eq!(wheel.cancel(handle), Some(id));
+            }
+            _ => {
+                now += match pseudo_random(&mut seed) % 3 {
+                    0 => pseudo_random(&mut seed) % 100,
+                    1 => pseudo_random(&mut seed) % 100_000,
+                    _ => pseudo_random(&mut seed) << 8,
+                };
+                let mut expired: Vec<_> = wheel.advance(now).collect();
+                assert!(expired.windows(2).all(|w| w[0].0 <= w[1].0));
+                let mut expected: Vec<_> = pending
+                    .iter()
+                    .filter(|&&(deadline, _, _)| deadline <= now)
+                    .map(|&(deadline, id, _)| (deadline, id))
+                    .collect();
+                pending.retain(|&(deadline, _, _)| deadline > now);
+                expected.sort();
+                expired.sort();
+                assert_eq!(expired, expected);
+            }
+        }
+        assert_eq!(wheel.len(), pending.len());
+    }
+}